@@ -185,6 +185,16 @@ impl ApexTimeP4 for DummyHypervisor {
     }
 }
 
+impl ApexTimeP1 for DummyHypervisor {
+    fn timed_wait(_delay_time: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        todo!()
+    }
+
+    fn replenish(_budget_time: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        todo!()
+    }
+}
+
 impl ApexErrorP4 for DummyHypervisor {
     fn report_application_message(_message: &[ApexByte]) -> Result<(), ErrorReturnCode> {
         todo!()
@@ -197,3 +207,45 @@ impl ApexErrorP4 for DummyHypervisor {
         todo!()
     }
 }
+
+impl ApexErrorP1 for DummyHypervisor {
+    fn create_error_handler(
+        _entry_point: SystemAddress,
+        _stack_size: StackSize,
+    ) -> Result<(), ErrorReturnCode> {
+        todo!()
+    }
+
+    fn get_error_status() -> Result<ErrorStatus, ErrorReturnCode> {
+        todo!()
+    }
+
+    fn configure_error_handler(
+        _concurrency_control: ErrorHandlerConcurrencyControl,
+        _processor_core_id: ProcessorCoreId,
+    ) -> Result<(), ErrorReturnCode> {
+        todo!()
+    }
+}
+
+impl ApexScheduleP2 for DummyHypervisor {
+    fn set_module_schedule(_schedule_id: ScheduleId) -> Result<(), ErrorReturnCode> {
+        todo!()
+    }
+
+    fn get_module_schedule_status() -> Result<ApexScheduleStatus, ErrorReturnCode> {
+        todo!()
+    }
+
+    fn get_module_schedule_id(_schedule_name: ScheduleName) -> Result<ScheduleId, ErrorReturnCode> {
+        todo!()
+    }
+}
+
+impl ApexMemoryBlockP2 for DummyHypervisor {
+    fn get_memory_block_status(
+        _memory_block_name: MemoryBlockName,
+    ) -> Result<ApexMemoryBlockStatus, ErrorReturnCode> {
+        todo!()
+    }
+}
@@ -9,7 +9,7 @@ fn main() {
 
 #[partition(crate::deps::dummy::DummyHypervisor)]
 mod hello {
-    #[sampling_out(name = "Ch1", msg_size = "10KB")]
+    #[sampling_out(name = "Ch1", msg_size = "10KB", required = true)]
     struct Channel1;
 
     #[sampling_in(refresh_period = "500ms")]
@@ -22,6 +22,38 @@ mod hello {
     #[queuing_in(msg_count = 10, msg_size = "2MB", discipline = "Priority")]
     struct ChannelFour;
 
+    #[sampling_out(name = "Att", ty = AttitudeMsg, required = true)]
+    struct Attitude;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Default)]
+    pub struct AttitudeMsg {
+        pub roll: f32,
+        pub pitch: f32,
+        pub yaw: f32,
+    }
+
+    // AttitudeMsg has no padding and consists only of floats
+    unsafe impl Pod for AttitudeMsg {}
+
+    #[memory_block(name = "SHM1", ty = SharedState, mode = "ReadWrite")]
+    struct Shared;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct SharedState {
+        pub counter: u64,
+    }
+
+    // SharedState consists only of an integer
+    unsafe impl Pod for SharedState {}
+
+    #[schedule(name = "CRUISE")]
+    struct Cruise;
+
+    #[schedule(name = "LANDING")]
+    struct Landing;
+
     #[start(cold)]
     fn cold_start(ctx: start::Context) {
         warm_start(ctx);
@@ -31,8 +63,16 @@ mod hello {
     fn warm_start(mut ctx: start::Context) {
         ctx.create_aperiodic2().unwrap().start().unwrap();
         ctx.create_periodic3().unwrap().start().unwrap();
+        ctx.create_control().unwrap().start().unwrap();
+        for i in 0..worker::INSTANCES {
+            // Workers are started on creation
+            ctx.create_worker(i).unwrap();
+        }
         ctx.create_channel_1().unwrap();
         ctx.create_channel_two().unwrap();
+        ctx.create_attitude().unwrap();
+        ctx.create_shared().unwrap();
+        ctx.create_error_handler().unwrap();
 
         // Maybe we do not always want to initialize channel3
         // ctx.create_channel_3().unwrap();
@@ -47,6 +87,21 @@ mod hello {
     )]
     fn aperiodic2(ctx: aperiodic2::Context) {
         ctx.get_time();
+        if let Ok(ScheduleStatus {
+            current_schedule: Some(Schedule::Cruise),
+            ..
+        }) = ctx.schedule_status()
+        {
+            ctx.switch_schedule(Schedule::Landing).ok();
+        }
+        // Channel1 is required, hence it was created during start
+        if let Err(e) = ctx
+            .channel_1
+            .send_array(b"Hello")
+            .context(Operation::SamplingWrite, ctx.channel_1.id())
+        {
+            e.report::<crate::deps::dummy::DummyHypervisor>().ok();
+        }
     }
 
     #[periodic(
@@ -56,5 +111,51 @@ mod hello {
         base_priority = 1,
         deadline = "Hard"
     )]
-    fn periodic3(_ctx: periodic3::Context) {}
+    fn periodic3(ctx: periodic3::Context) {
+        ctx.attitude.send(&AttitudeMsg::default()).ok();
+        if let Ok(shared) = ctx.shared {
+            let state = shared.read();
+            shared.write(&SharedState {
+                counter: state.counter + 1,
+            });
+        }
+    }
+
+    #[periodic(
+        period = "10ms",
+        time_capacity = "Infinite",
+        stack_size = "10KB",
+        base_priority = 1,
+        deadline = "Hard",
+        body = "Step",
+        wait_error = "Stop",
+        budget = "2ms"
+    )]
+    fn control(ctx: control::Context) {
+        // Called once per period by the generated loop
+        ctx.get_time();
+    }
+
+    #[aperiodic(
+        name = "wrk",
+        time_capacity = "Infinite",
+        stack_size = "10KB",
+        base_priority = 1,
+        deadline = "Soft",
+        instances = 3,
+        core_affinity = 0,
+        autostart = true
+    )]
+    fn worker(ctx: worker::Context) {
+        let _me = ctx.worker[ctx.instance];
+    }
+
+    #[error_handler(stack_size = "8KB")]
+    fn error_handler(ctx: error_handler::Context) {
+        if let Ok(status) = ctx.error_status() {
+            ctx.report_application_message(&status.message[..status.length as usize])
+                .ok();
+        }
+        ctx.stop_self();
+    }
 }
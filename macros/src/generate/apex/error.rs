@@ -4,15 +4,24 @@ use crate::generate::context::Context;
 
 impl Context {
     pub fn gen_error(&self) -> impl Iterator<Item = ItemImpl> {
-        [
-            // self.gen_error_p1(),
-            self.gen_error_p4(),
-        ]
-        .into_iter()
+        std::iter::once(self.gen_error_p4()).chain(self.gen_error_p1())
+    }
+
+    /// The error status is only available to the error handler
+    pub fn gen_error_p1(&self) -> Option<ItemImpl> {
+        if !matches!(self, Context::ErrorHandler) {
+            return None;
+        }
+        let ctx = self.get_context_ident();
+        let functions = vec![self.gen_error_error_status()];
+        Some(parse_quote! {
+            impl<'a, Hypervisor: ApexErrorP1Ext> #ctx <'a, Hypervisor> {
+                #(#functions)*
+            }
+        })
     }
 
     pub fn gen_error_p4(&self) -> ItemImpl {
-        // TODO filter depending on start/periodic/aperiodic context
         let ctx = self.get_context_ident();
         let functions = vec![
             self.gen_error_report_application_message(),
@@ -41,5 +50,11 @@ impl Context {
         }
     }
 
-    // TODO ErrorP1
+    pub fn gen_error_error_status(&self) -> ItemFn {
+        parse_quote! {
+            pub fn error_status(&self) -> Result<ErrorStatus, Error> {
+                <Hypervisor as ApexErrorP1Ext>::error_status()
+            }
+        }
+    }
 }
@@ -1,5 +1,7 @@
 pub mod error;
 pub mod partition;
+pub mod process;
 pub mod queuing;
 pub mod sampling;
+pub mod schedules;
 pub mod time;
@@ -1,19 +1,29 @@
-use syn::{parse_quote, ItemImpl};
+use syn::{parse_quote, ImplItemFn, ItemImpl};
 
 use crate::generate::context::Context;
 
 impl Context {
     pub fn gen_partition(&self) -> ItemImpl {
         let ctx = self.get_context_ident();
+        let start_condition: Option<ImplItemFn> = matches!(self, Context::Start).then(|| {
+            parse_quote! {
+                /// Reason for the current cold or warm start
+                pub fn start_condition(&self) -> a653rs::prelude::StartCondition {
+                    Partition::get_status().start_condition
+                }
+            }
+        });
         parse_quote! {
             impl<'a, H> #ctx <'a, H> {
                 pub fn get_partition_status(&self) -> a653rs::prelude::PartitionStatus {
                     Partition::get_status()
                 }
 
-                pub fn set_partition_mode(&self, mode: a653rs::prelude::OperatingMode) -> Result<(), Error> {
-                    Partition::set_mode(mode)
+                pub fn set_partition_mode<M: a653rs::prelude::mode::Restart>(&self) -> Result<(), Error> {
+                    Partition::set_mode::<M>()
                 }
+
+                #start_condition
             }
         }
     }
@@ -0,0 +1,106 @@
+use syn::{parse_quote, ItemFn, ItemImpl};
+
+use crate::generate::context::Context;
+
+impl Context {
+    pub fn gen_process(&self) -> impl Iterator<Item = ItemImpl> {
+        std::iter::once(self.gen_process_p1())
+    }
+
+    pub fn gen_process_p1(&self) -> ItemImpl {
+        let ctx = self.get_context_ident();
+        let mut functions = vec![
+            self.gen_process_get_process(),
+            self.gen_process_get_my_processor_core_id(),
+        ];
+        match self {
+            Context::Start => {}
+            Context::Periodic => functions.extend([
+                self.gen_process_get_my_index(),
+                self.gen_process_lock_preemption(),
+                self.gen_process_unlock_preemption(),
+                self.gen_process_preemption_lock(),
+                self.gen_process_stop_self(),
+            ]),
+            // Only aperiodic processes may suspend themselves
+            Context::Aperiodic => functions.extend([
+                self.gen_process_get_my_index(),
+                self.gen_process_suspend_self(),
+                self.gen_process_lock_preemption(),
+                self.gen_process_unlock_preemption(),
+                self.gen_process_preemption_lock(),
+                self.gen_process_stop_self(),
+            ]),
+            Context::ErrorHandler => functions.push(self.gen_process_stop_self()),
+        }
+        parse_quote! {
+            impl<'a, Hypervisor: ApexProcessP1Ext> #ctx <'a, Hypervisor> {
+                #(#functions)*
+            }
+        }
+    }
+
+    pub fn gen_process_get_process(&self) -> ItemFn {
+        parse_quote! {
+            pub fn get_process(&self, name: Name) -> Result<Process<Hypervisor>, Error> {
+                <Hypervisor as ApexProcessP1Ext>::get_process(name)
+            }
+        }
+    }
+
+    pub fn gen_process_get_my_processor_core_id(&self) -> ItemFn {
+        parse_quote! {
+            pub fn get_my_processor_core_id(&self) -> ProcessorCoreId {
+                Process::<Hypervisor>::get_my_processor_core_id()
+            }
+        }
+    }
+
+    pub fn gen_process_get_my_index(&self) -> ItemFn {
+        parse_quote! {
+            pub fn get_my_index(&self) -> Result<ProcessIndex, Error> {
+                Process::<Hypervisor>::get_my_index()
+            }
+        }
+    }
+
+    pub fn gen_process_suspend_self(&self) -> ItemFn {
+        parse_quote! {
+            pub fn suspend_self(&self, time_out: SystemTime) -> Result<(), Error> {
+                Process::<Hypervisor>::suspend_self(time_out)
+            }
+        }
+    }
+
+    pub fn gen_process_stop_self(&self) -> ItemFn {
+        parse_quote! {
+            pub fn stop_self(&self) {
+                Process::<Hypervisor>::stop_self()
+            }
+        }
+    }
+
+    pub fn gen_process_lock_preemption(&self) -> ItemFn {
+        parse_quote! {
+            pub fn lock_preemption(&self) -> Result<LockLevel, Error> {
+                Process::<Hypervisor>::lock_preemption()
+            }
+        }
+    }
+
+    pub fn gen_process_unlock_preemption(&self) -> ItemFn {
+        parse_quote! {
+            pub fn unlock_preemption(&self) -> Result<LockLevel, Error> {
+                Process::<Hypervisor>::unlock_preemption()
+            }
+        }
+    }
+
+    pub fn gen_process_preemption_lock(&self) -> ItemFn {
+        parse_quote! {
+            pub fn preemption_lock(&self) -> Result<PreemptionGuard<Hypervisor>, Error> {
+                Process::<Hypervisor>::preemption_lock()
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use syn::{parse_quote, ItemFn, ItemImpl};
+
+use crate::generate::context::Context;
+
+impl Context {
+    pub fn gen_schedules(&self) -> impl Iterator<Item = ItemImpl> {
+        std::iter::once(self.gen_schedules_p2())
+    }
+
+    /// Switching the schedule is left to the processes of the partition
+    pub fn gen_schedules_p2(&self) -> ItemImpl {
+        let ctx = self.get_context_ident();
+        let mut functions = vec![
+            self.gen_schedules_get_module_schedule_status(),
+            self.gen_schedules_get_module_schedule_id(),
+        ];
+        if !matches!(self, Context::Start) {
+            functions.push(self.gen_schedules_set_module_schedule());
+        }
+        parse_quote! {
+            impl<'a, Hypervisor: ApexScheduleP2Ext> #ctx <'a, Hypervisor> {
+                #(#functions)*
+            }
+        }
+    }
+
+    pub fn gen_schedules_set_module_schedule(&self) -> ItemFn {
+        parse_quote! {
+            pub fn set_module_schedule(&self, schedule_id: ScheduleId) -> Result<(), Error> {
+                <Hypervisor as ApexScheduleP2Ext>::set_module_schedule(schedule_id)
+            }
+        }
+    }
+
+    pub fn gen_schedules_get_module_schedule_status(&self) -> ItemFn {
+        parse_quote! {
+            pub fn get_module_schedule_status(&self) -> Result<ScheduleStatus, Error> {
+                <Hypervisor as ApexScheduleP2Ext>::get_module_schedule_status()
+            }
+        }
+    }
+
+    pub fn gen_schedules_get_module_schedule_id(&self) -> ItemFn {
+        parse_quote! {
+            pub fn get_module_schedule_id(&self, schedule_name: ScheduleName) -> Result<ScheduleId, Error> {
+                <Hypervisor as ApexScheduleP2Ext>::get_module_schedule_id(schedule_name)
+            }
+        }
+    }
+}
@@ -4,24 +4,29 @@ use crate::generate::context::Context;
 
 impl Context {
     pub fn gen_time(&self) -> impl Iterator<Item = ItemImpl> {
-        [self.gen_time_p1(), self.gen_time_p4()].into_iter()
+        self.gen_time_p1()
+            .into_iter()
+            .chain(std::iter::once(self.gen_time_p4()))
     }
 
-    pub fn gen_time_p1(&self) -> ItemImpl {
-        // TODO filter depending on start/periodic/aperiodic context
+    /// Waiting and replenishing is only possible for processes,
+    /// neither for the partition main thread nor for the error handler
+    pub fn gen_time_p1(&self) -> Option<ItemImpl> {
+        if !matches!(self, Context::Periodic | Context::Aperiodic) {
+            return None;
+        }
         let ctx = self.get_context_ident();
         let functions = vec![self.gen_time_timed_wait(), self.gen_time_replenish()];
-        parse_quote! {
+        Some(parse_quote! {
             impl<'a, Hypervisor: ApexTimeP1Ext> #ctx <'a, Hypervisor> {
                 #(#functions)*
             }
-        }
+        })
     }
 
     pub fn gen_time_p4(&self) -> ItemImpl {
-        // TODO filter depending on start/periodic/aperiodic context
         let ctx = self.get_context_ident();
-        let mut functions = vec![self.gen_time_get_time()];
+        let mut functions = vec![self.gen_time_get_time(), self.gen_time_now()];
         if matches!(self, Context::Periodic) {
             functions.push(self.gen_time_periodic_wait())
         }
@@ -66,4 +71,12 @@ impl Context {
             }
         }
     }
+
+    pub fn gen_time_now(&self) -> ItemFn {
+        parse_quote! {
+            pub fn now(&self) -> Result<Instant, Error> {
+                <Hypervisor as ApexTimeP4Ext>::now()
+            }
+        }
+    }
 }
@@ -1,10 +1,11 @@
 use convert_case::{Case, Casing};
 use quote::format_ident;
 use syn::{
-    parse_quote, ExprCall, Ident, ItemConst, ItemImpl, ItemMod, ItemStatic, LitByteStr, LitStr,
-    Path,
+    parse_quote, Expr, ExprCall, Ident, ItemConst, ItemImpl, ItemMod, ItemStatic, ItemType,
+    ItemUse, Path,
 };
 
+use crate::generate::util::gen_name;
 use crate::parse::channel::Channel;
 
 impl Channel {
@@ -17,10 +18,23 @@ impl Channel {
         )
     }
 
+    /// Alias for the message type of a typed channel, named after the channel
+    pub fn gen_msg_type_alias(&self) -> Option<ItemType> {
+        let ident = self.ident();
+        let ty = self.ty()?;
+        Some(parse_quote!(type #ident = #ty;))
+    }
+
+    pub fn gen_msg_type_use(&self) -> Option<ItemUse> {
+        self.ty()?;
+        let ident = self.ident();
+        Some(parse_quote!(use super:: #ident;))
+    }
+
     pub fn gen_static_value(&self) -> ItemStatic {
         let ch_type = self.typ();
         parse_quote! {
-            pub static mut VALUE : Option< #ch_type > = None;
+            pub static VALUE : StartCell< #ch_type > = StartCell::new();
         }
     }
 
@@ -33,10 +47,7 @@ impl Channel {
                 pub fn #create_name(&mut self) -> Result<(), Error>{
                     use core::str::FromStr;
                     let channel = self.ctx. #create_channel_call ?;
-                    // This is safe because during cold/warm start only one thread works
-                    unsafe {
-                        VALUE = Some( channel );
-                    }
+                    VALUE.set(self.ctx, channel)?;
                     Ok(())
                 }
             }
@@ -44,7 +55,22 @@ impl Channel {
     }
 
     pub fn gen_create_channel_call(&self) -> ExprCall {
+        let typed = self.ty().is_some();
         match self {
+            Channel::SamplingOut(_, _) if typed => {
+                parse_quote!(create_typed_sampling_port_source(NAME))
+            }
+            Channel::SamplingIn(_, _) if typed => {
+                parse_quote!(create_typed_sampling_port_destination(NAME, REFRESH_PERIOD))
+            }
+            Channel::QueuingOut(_, _) if typed => {
+                parse_quote!(create_typed_queuing_port_sender(NAME, NB_MSGS, DISCIPLINE))
+            }
+            Channel::QueuingIn(_, _) if typed => {
+                parse_quote!(create_typed_queuing_port_receiver(
+                    NAME, NB_MSGS, DISCIPLINE
+                ))
+            }
             Channel::SamplingOut(_, _) => {
                 parse_quote!(create_const_sampling_port_source(NAME))
             }
@@ -75,27 +101,20 @@ impl Channel {
     }
 
     pub fn gen_const_name(&self) -> syn::Result<ItemConst> {
-        const LEN: usize = 32;
-        let name = self.name().to_string();
-        let len = name.bytes().len();
-        if len > LEN {
-            return Err(syn::Error::new_spanned(
-                name,
-                format!("max name length is {LEN} bytes"),
-            ));
-        }
-        let name = &format!("{name}{:\0<1$}", "", LEN - len);
-        let lit_name: LitStr = parse_quote!(#name);
-        let name = LitByteStr::new(name.as_bytes(), lit_name.span());
-
+        let name = gen_name(&self.name().to_string(), self.ident())?;
         Ok(parse_quote! {
-             pub(super) const NAME: Name =
-                Name::new( * #name );
+             pub(super) const NAME: Name = #name;
         })
     }
 
     pub fn gen_const_msg_size(&self) -> ItemConst {
-        let msg_size = self.msg_size() as u32;
+        if self.ty().is_some() {
+            let ident = self.ident();
+            return parse_quote! {
+                 pub(super) const MSG_SIZE: MessageSize = <#ident as PodExt>::SIZE;
+            };
+        }
+        let msg_size = self.msg_size().unwrap_or_default() as u32;
         parse_quote! {
              pub(super) const MSG_SIZE: MessageSize = #msg_size;
         }
@@ -125,14 +144,40 @@ impl Channel {
         })
     }
 
+    /// Entry of the partition manifest, built from the consts of the channel mod
+    pub fn gen_manifest(&self) -> Expr {
+        let name = self.gen_snake_ident();
+        let kind: Expr = match self {
+            Channel::SamplingOut(..) => parse_quote!(ChannelKind::SamplingSource),
+            Channel::SamplingIn(..) => parse_quote! {
+                ChannelKind::SamplingDestination { refresh_period: #name::REFRESH_PERIOD }
+            },
+            Channel::QueuingOut(..) => parse_quote! {
+                ChannelKind::QueuingSender { nb_msgs: #name::NB_MSGS, discipline: #name::DISCIPLINE }
+            },
+            Channel::QueuingIn(..) => parse_quote! {
+                ChannelKind::QueuingReceiver { nb_msgs: #name::NB_MSGS, discipline: #name::DISCIPLINE }
+            },
+        };
+        parse_quote! {
+            ChannelManifest {
+                name: #name::NAME,
+                msg_size: #name::MSG_SIZE,
+                kind: #kind,
+            }
+        }
+    }
+
     pub fn gen_channel_mod(&self) -> syn::Result<ItemMod> {
         let name = self.gen_snake_ident();
         let consts = self.gen_consts()?;
         let static_value = self.gen_static_value();
         let create_fn = self.gen_create_fn();
+        let msg_type_use = self.gen_msg_type_use();
         Ok(parse_quote! {
             mod #name {
                 use super::Hypervisor;
+                #msg_type_use
                 use a653rs::prelude::*;
 
                 #create_fn
@@ -1,8 +1,7 @@
-use convert_case::{Case, Casing};
-use quote::{format_ident, quote};
+use quote::quote;
 use strum::{Display, EnumIter, IntoEnumIterator};
 use syn::parse::Parser;
-use syn::{parse_quote, Field, Ident, Item, ItemImpl};
+use syn::{parse_quote, Field, FieldValue, Ident, Item, ItemImpl};
 
 use crate::parse::process::{Process, SystemTime};
 use crate::partition::Partition;
@@ -12,6 +11,7 @@ pub enum Context {
     Start,
     Periodic,
     Aperiodic,
+    ErrorHandler,
 }
 
 impl Context {
@@ -23,15 +23,14 @@ impl Context {
     }
 
     pub fn gen_all(part: &Partition) -> impl Iterator<Item = Item> + '_ {
-        Self::gen_contexts(part)
-            .map(Into::into)
-            .chain(Self::gen_all_extensions().map(Into::into))
+        Self::gen_contexts(part).chain(Self::gen_all_extensions().map(Into::into))
     }
 
     fn gen_contexts(part: &Partition) -> impl Iterator<Item = Item> + '_ {
         Self::gen_start_context()
             .chain(Self::Periodic.gen_process_context(part))
             .chain(Self::Aperiodic.gen_process_context(part))
+            .chain(Self::gen_error_handler_context(part))
     }
 
     fn gen_start_context() -> impl Iterator<Item = Item> {
@@ -40,6 +39,8 @@ impl Context {
             struct #name <'a, H> {
                 _p: core::marker::PhantomData<core::sync::atomic::AtomicPtr<H>>,
                 ctx: &'a mut a653rs::prelude::StartContext<Hypervisor>,
+                /// Number of processes created so far, which is the index of the last created process
+                processes: a653rs::prelude::ProcessIndex,
             }
         };
 
@@ -49,6 +50,7 @@ impl Context {
                     Self {
                         _p: core::marker::PhantomData::default(),
                         ctx,
+                        processes: 0,
                     }
                 }
             }
@@ -59,22 +61,61 @@ impl Context {
     fn gen_process_context(&self, part: &Partition) -> impl Iterator<Item = Item> {
         let name = self.get_context_ident();
         let fields = part.gen_context_fields();
-        let field_names: Vec<_> = part.gen_context_fields().filter_map(|f| f.ident).collect();
+        let field_inits = part.gen_context_field_inits();
+        let periodic = matches!(self, Context::Periodic);
+        let execution_time = periodic.then(|| {
+            quote! {
+                /// Execution time of the previous activation of a `body = "Step"` process with a `budget`
+                execution_time: Option<core::time::Duration>,
+            }
+        });
+        let execution_time_init = periodic.then(|| quote!(execution_time: None,));
         let st = parse_quote! {
             struct #name <'a, H> {
                 _p: core::marker::PhantomData<core::sync::atomic::AtomicPtr<H>>,
                 proc_self: &'a Process<Hypervisor>,
+                /// Index of this process instance, always `0` for single-instance processes
+                instance: usize,
+                #execution_time
                 #(#fields),*
             }
         };
         let im = parse_quote! {
             impl<'a, H> #name <'a, H>{
-                pub fn new(proc_self: &'a Process<Hypervisor>) -> Self {
-                    Self{
+                /// Fails with [Error::Uninitialized] if a required resource was not created
+                pub fn new(proc_self: &'a Process<Hypervisor>, instance: usize) -> Result<Self, Error> {
+                    Ok(Self{
                         _p: core::marker::PhantomData::default(),
                         proc_self,
-                        #(#field_names: unsafe{ #field_names::VALUE.as_ref() }),*
-                    }
+                        instance,
+                        #execution_time_init
+                        #(#field_inits),*
+                    })
+                }
+            }
+        };
+
+        [st, im].into_iter()
+    }
+
+    fn gen_error_handler_context(part: &Partition) -> impl Iterator<Item = Item> {
+        let name = Context::ErrorHandler.get_context_ident();
+        let fields = part.gen_context_fields();
+        let field_inits = part.gen_context_field_inits();
+        let st = parse_quote! {
+            struct #name <'a, H> {
+                _p: core::marker::PhantomData<core::sync::atomic::AtomicPtr<&'a H>>,
+                #(#fields),*
+            }
+        };
+        let im = parse_quote! {
+            impl<'a, H> #name <'a, H>{
+                /// Fails with [Error::Uninitialized] if a required resource was not created
+                pub fn new() -> Result<Self, Error> {
+                    Ok(Self{
+                        _p: core::marker::PhantomData::default(),
+                        #(#field_inits),*
+                    })
                 }
             }
         };
@@ -86,6 +127,8 @@ impl Context {
         std::iter::once(self.gen_partition())
             .chain(self.gen_time())
             .chain(self.gen_error())
+            .chain(self.gen_process())
+            .chain(self.gen_schedules())
     }
 
     fn gen_all_extensions() -> impl Iterator<Item = ItemImpl> {
@@ -97,6 +140,7 @@ impl Context {
             Context::Start => parse_quote!(StartContext),
             Context::Periodic => parse_quote!(PeriodicContext),
             Context::Aperiodic => parse_quote!(AperiodicContext),
+            Context::ErrorHandler => parse_quote!(ErrorHandlerContext),
         }
     }
 }
@@ -105,29 +149,71 @@ impl Partition {
     pub fn gen_context_process_fields(&self) -> impl Iterator<Item = Field> + '_ {
         self.processes.iter().map(|p| {
             let ident = &p.ident;
-            Field::parse_named
-                .parse2(quote!(#ident: Option<&'a Process<Hypervisor>>))
-                .unwrap()
+            let typ = match p.required {
+                true => quote!(&'a Process<Hypervisor>),
+                false => quote!(Result<&'a Process<Hypervisor>, Error>),
+            };
+            let field = match p.instances {
+                Some(_) => quote!(#ident: [#typ; #ident::INSTANCES]),
+                None => quote!(#ident: #typ),
+            };
+            Field::parse_named.parse2(field).unwrap()
         })
     }
 
+    fn gen_context_field_inits(&self) -> impl Iterator<Item = FieldValue> + '_ {
+        // Required resources were verified by the partition start, so `?` only fails on a misuse
+        let procs = self.processes.iter().map(|p| {
+            let ident = &p.ident;
+            match (p.instances, p.required) {
+                (Some(_), true) => parse_quote!(#ident: StartCell::get_all(&#ident::VALUE)?),
+                (Some(_), false) => {
+                    parse_quote!(#ident: core::array::from_fn(|i| #ident::VALUE[i].get()))
+                }
+                (None, true) => parse_quote!(#ident: #ident::VALUE.get()?),
+                (None, false) => parse_quote!(#ident: #ident::VALUE.get()),
+            }
+        });
+        let channels = self.channel.iter().map(|c| {
+            let ident = c.gen_snake_ident();
+            let question = c.required().then(|| quote!(?));
+            parse_quote!(#ident: #ident::VALUE.get() #question)
+        });
+        let blocks = self.memory_blocks.iter().map(|b| {
+            let ident = b.gen_snake_ident();
+            let question = b.required.then(|| quote!(?));
+            parse_quote!(#ident: #ident::VALUE.get() #question)
+        });
+        procs.chain(channels).chain(blocks)
+    }
+
     pub fn gen_context_channel_fields(&self) -> impl Iterator<Item = Field> + '_ {
         self.channel.iter().map(|c| {
-            let ident = &c.ident();
-            let ident = format_ident!(
-                "{}",
-                ident.to_string().to_case(Case::Snake),
-                span = ident.span()
-            );
+            let ident = c.gen_snake_ident();
             let typ = c.typ();
-            Field::parse_named
-                .parse2(quote!(#ident: Option< &'a #typ >))
-                .unwrap()
+            let field = match c.required() {
+                true => quote!(#ident: &'a #typ),
+                false => quote!(#ident: Result< &'a #typ, Error >),
+            };
+            Field::parse_named.parse2(field).unwrap()
+        })
+    }
+
+    pub fn gen_context_memory_block_fields(&self) -> impl Iterator<Item = Field> + '_ {
+        self.memory_blocks.iter().map(|b| {
+            let ident = b.gen_snake_ident();
+            let typ = b.typ();
+            let field = match b.required {
+                true => quote!(#ident: &'a #typ),
+                false => quote!(#ident: Result< &'a #typ, Error >),
+            };
+            Field::parse_named.parse2(field).unwrap()
         })
     }
 
     pub fn gen_context_fields(&self) -> impl Iterator<Item = Field> + '_ {
         self.gen_context_process_fields()
             .chain(self.gen_context_channel_fields())
+            .chain(self.gen_context_memory_block_fields())
     }
 }
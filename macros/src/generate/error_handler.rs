@@ -0,0 +1,67 @@
+use quote::format_ident;
+use syn::{parse_quote, ItemConst, ItemFn, ItemImpl, ItemMod};
+
+use super::context::Context;
+use crate::parse::error_handler::ErrorHandler;
+
+impl ErrorHandler {
+    fn gen_wrapper_fn(&self) -> ItemFn {
+        let ident = &self.ident;
+        parse_quote! {
+            pub(super) extern "C" fn wrapper () {
+                // Only fails if the partition was started without its required resources
+                let Ok(ctx) = Context::new() else {
+                    return Process::<Hypervisor>::stop_self();
+                };
+                super:: #ident(ctx)
+            }
+        }
+    }
+
+    pub fn gen_create_fn(&self) -> ItemImpl {
+        let create_ident = format_ident!("create_{}", self.ident);
+        let configure_ident = format_ident!("configure_{}", self.ident);
+        parse_quote! {
+            impl<'a> super:: StartContext<'a, Hypervisor> {
+                pub fn #create_ident(&mut self) -> Result<(), Error>{
+                    self.ctx.set_error_handler(wrapper, STACK_SIZE)
+                }
+
+                pub fn #configure_ident(
+                    &mut self,
+                    concurrency_control: ErrorHandlerConcurrencyControl,
+                    processor_core_id: ProcessorCoreId,
+                ) -> Result<(), Error>{
+                    self.ctx.configure_error_handler(concurrency_control, processor_core_id)
+                }
+            }
+        }
+    }
+
+    pub fn gen_const_stack_size(&self) -> ItemConst {
+        let stack_size = self.stack_size.as_u64() as u32;
+        parse_quote! {
+             pub(super) const STACK_SIZE: StackSize = #stack_size ;
+        }
+    }
+
+    pub fn gen_error_handler_mod(&self) -> ItemMod {
+        let ident = &self.ident;
+        let wrapper = self.gen_wrapper_fn();
+        let stack_size = self.gen_const_stack_size();
+        let create_fn = self.gen_create_fn();
+        let context_ident = Context::ErrorHandler.get_context_ident();
+        parse_quote! {
+            mod #ident {
+                use a653rs::prelude::*;
+                use super::Hypervisor;
+
+                pub(super) type Context<'a> = super:: #context_ident <'a, Hypervisor> ;
+
+                #wrapper
+                #create_fn
+                #stack_size
+            }
+        }
+    }
+}
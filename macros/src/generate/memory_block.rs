@@ -0,0 +1,84 @@
+use convert_case::{Case, Casing};
+use quote::format_ident;
+use syn::{parse_quote, Ident, ItemConst, ItemImpl, ItemMod, ItemType, Path, Type};
+
+use crate::generate::util::gen_name;
+use crate::parse::memory_block::{MemoryBlock, MemoryBlockMode};
+
+impl From<MemoryBlockMode> for Path {
+    fn from(mode: MemoryBlockMode) -> Self {
+        let var = format_ident!("{}", mode.to_string());
+        parse_quote!(MemoryBlockMode:: #var)
+    }
+}
+
+impl MemoryBlock {
+    pub fn gen_snake_ident(&self) -> Ident {
+        format_ident!(
+            "{}",
+            self.ident.to_string().to_case(Case::Snake),
+            span = self.ident.span()
+        )
+    }
+
+    /// Typed view on the memory block, which refers to the value type by the memory block ident
+    pub fn typ(&self) -> Type {
+        let ident = &self.ident;
+        match self.mode {
+            MemoryBlockMode::Read => parse_quote!(TypedMemoryBlock::< #ident , Hypervisor>),
+            MemoryBlockMode::ReadWrite => {
+                parse_quote!(TypedMemoryBlockMut::< #ident , Hypervisor>)
+            }
+        }
+    }
+
+    /// Alias for the value type of the memory block, named after the memory block
+    pub fn gen_type_alias(&self) -> ItemType {
+        let ident = &self.ident;
+        let ty = &self.ty;
+        parse_quote!(type #ident = #ty;)
+    }
+
+    pub fn gen_create_fn(&self) -> ItemImpl {
+        let name = self.gen_snake_ident();
+        let create_name = format_ident!("create_{name}");
+        parse_quote! {
+            impl<'a> super:: StartContext<'a, Hypervisor> {
+                pub fn #create_name(&mut self) -> Result<(), Error>{
+                    // Size, alignment and write access are checked by the typed view
+                    let block = <Hypervisor as ApexMemoryBlockP2Ext>::get_memory_block(NAME)?;
+                    VALUE.set(self.ctx, block.try_into()?)?;
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    pub fn gen_consts(&self) -> syn::Result<Vec<ItemConst>> {
+        let name = gen_name(&self.name.to_string(), &self.ident)?;
+        let mode: Path = self.mode.into();
+        Ok(vec![
+            parse_quote!(pub(super) const NAME: Name = #name;),
+            parse_quote!(pub(super) const MODE: MemoryBlockMode = #mode;),
+        ])
+    }
+
+    pub fn gen_memory_block_mod(&self) -> syn::Result<ItemMod> {
+        let name = self.gen_snake_ident();
+        let ident = &self.ident;
+        let typ = self.typ();
+        let consts = self.gen_consts()?;
+        let create_fn = self.gen_create_fn();
+        Ok(parse_quote! {
+            mod #name {
+                use super::Hypervisor;
+                use super:: #ident;
+                use a653rs::prelude::*;
+
+                #create_fn
+                #(#consts)*
+                pub static VALUE : StartCell< #typ > = StartCell::new();
+            }
+        })
+    }
+}
@@ -1,7 +1,9 @@
 pub mod apex;
 pub mod channel;
 pub mod context;
+pub mod error_handler;
+pub mod memory_block;
 pub mod partition;
 pub mod process;
-pub mod traits;
+pub mod schedule;
 pub mod util;
@@ -1,7 +1,12 @@
-use syn::{parse_quote, ItemImpl, ItemMod, ItemStruct, ItemType};
+use std::time::Duration;
+
+use syn::{parse_quote, Expr, ItemConst, ItemImpl, ItemMod, ItemStruct, ItemType, Stmt};
 
 use super::context::Context;
 use crate::parse::channel::Channel;
+use crate::parse::error_handler::ErrorHandler;
+use crate::parse::memory_block::MemoryBlock;
+use crate::parse::partition::Idle;
 use crate::parse::process::Process;
 use crate::partition::Partition;
 
@@ -29,6 +34,29 @@ impl Partition {
             .into_iter())
     }
 
+    pub fn gen_channel_type_aliases(&self) -> impl Iterator<Item = ItemType> + '_ {
+        self.channel.iter().filter_map(Channel::gen_msg_type_alias)
+    }
+
+    pub fn gen_memory_block_type_aliases(&self) -> impl Iterator<Item = ItemType> + '_ {
+        self.memory_blocks.iter().map(MemoryBlock::gen_type_alias)
+    }
+
+    pub fn gen_memory_block_mods(&self) -> syn::Result<impl Iterator<Item = ItemMod>> {
+        Ok(self
+            .memory_blocks
+            .iter()
+            .map(MemoryBlock::gen_memory_block_mod)
+            .collect::<syn::Result<Vec<ItemMod>>>()?
+            .into_iter())
+    }
+
+    pub fn gen_error_handler_mod(&self) -> Option<ItemMod> {
+        self.error_handler
+            .as_ref()
+            .map(ErrorHandler::gen_error_handler_mod)
+    }
+
     pub fn gen_start_mod(&self) -> ItemMod {
         let ctx = Context::Start.get_context_ident();
         parse_quote! {
@@ -45,21 +73,166 @@ impl Partition {
         }
     }
 
+    pub fn gen_manifest(&self) -> ItemConst {
+        let channels = self.channel.iter().map(Channel::gen_manifest);
+        let processes = self.processes.iter().map(Process::gen_manifest);
+        parse_quote! {
+            pub const MANIFEST: PartitionManifest = PartitionManifest {
+                channels: &[ #(#channels),* ],
+                processes: &[ #(#processes),* ],
+            };
+        }
+    }
+
+    pub fn gen_manifest_impl(&self) -> ItemImpl {
+        parse_quote! {
+            impl Partition {
+                /// Resources declared by this partition
+                pub const fn manifest() -> &'static PartitionManifest {
+                    &MANIFEST
+                }
+            }
+        }
+    }
+
+    pub fn gen_idle(&self) -> Option<ItemConst> {
+        let idle: Expr = match self.idle.as_ref()? {
+            Idle::Spin => parse_quote!(IdlePolicy::Spin),
+            Idle::StopSelf => parse_quote!(IdlePolicy::StopSelf),
+            Idle::TimedWait(delay) => {
+                let delay = gen_duration(Duration::from(*delay));
+                parse_quote!(IdlePolicy::TimedWait(#delay))
+            }
+            Idle::Yield(path) => parse_quote!(IdlePolicy::Yield(#path)),
+        };
+        Some(parse_quote! {
+            pub const IDLE: IdlePolicy = #idle;
+        })
+    }
+
+    /// `run_idle` and `try_run_idle` using the idle policy,
+    /// only requiring the hypervisor traits used by the policy
+    pub fn gen_idle_impl(&self) -> Option<ItemImpl> {
+        let idle: Expr = match self.idle.as_ref()? {
+            Idle::Spin => parse_quote! {
+                || {
+                    core::hint::spin_loop();
+                    Ok(())
+                }
+            },
+            Idle::StopSelf => parse_quote! {
+                || {
+                    Process::<Hypervisor>::stop_self();
+                    // Stopping the initialization process never returns, if supported
+                    Err(Error::InvalidMode)
+                }
+            },
+            Idle::TimedWait(delay) => {
+                let delay = gen_duration(Duration::from(*delay));
+                parse_quote!(|| <Hypervisor as ApexTimeP1Ext>::timed_wait(#delay))
+            }
+            Idle::Yield(path) => parse_quote! {
+                || {
+                    #path();
+                    Ok(())
+                }
+            },
+        };
+        Some(parse_quote! {
+            impl Partition {
+                /// Start the partition and idle according to [IDLE] afterwards
+                ///
+                /// # Errors
+                /// - [Error]: idling failed, see [IdlePolicy]
+                pub fn run_idle(self) -> Result<core::convert::Infallible, Error> {
+                    a653rs::prelude::PartitionExt::run_with_idle(self, #idle)
+                }
+
+                /// Like [Partition::run_idle], but handling a failed start according to `policy`
+                ///
+                /// # Errors
+                /// - [StartError]: the start failed
+                /// - [StartError::Idle]: idling failed, see [IdlePolicy]
+                pub fn try_run_idle(
+                    self,
+                    policy: StartFailurePolicy,
+                ) -> Result<core::convert::Infallible, StartError> {
+                    a653rs::prelude::PartitionExt::try_run(self, policy, #idle)
+                }
+            }
+        })
+    }
+
+    /// Checks for all required processes, channels and memory blocks having been created
+    pub fn gen_required_checks(&self) -> Vec<Stmt> {
+        let procs = self.processes.iter().filter(|p| p.required).map(|p| {
+            let ident = &p.ident;
+            match p.instances {
+                Some(_) => parse_quote! {
+                    for (value, name) in #ident::VALUE.iter().zip(#ident::NAMES) {
+                        if !value.is_initialized() {
+                            return Err(StartError::MissingRequired(name));
+                        }
+                    }
+                },
+                None => parse_quote! {
+                    if !#ident::VALUE.is_initialized() {
+                        return Err(StartError::MissingRequired(#ident::NAME));
+                    }
+                },
+            }
+        });
+        let channels = self
+            .channel
+            .iter()
+            .filter(|c| c.required())
+            .map(|c| c.gen_snake_ident());
+        let blocks = self
+            .memory_blocks
+            .iter()
+            .filter(|b| b.required)
+            .map(|b| b.gen_snake_ident());
+        let others = channels.chain(blocks).map(|ident| {
+            parse_quote! {
+                if !#ident::VALUE.is_initialized() {
+                    return Err(StartError::MissingRequired(#ident::NAME));
+                }
+            }
+        });
+        procs.chain(others).collect()
+    }
+
     pub fn gen_impl(&self) -> ItemImpl {
         let cold_start = &self.cold_start.sig.ident;
         let warm_start = &self.warm_start.sig.ident;
+        let checks = self.gen_required_checks();
+        let resolve: Option<Stmt> =
+            (!self.schedules.is_empty()).then(|| parse_quote!(schedule::resolve(ctx);));
         parse_quote! {
             impl a653rs::prelude::Partition<Hypervisor> for Partition{
                 fn cold_start(&self, ctx: &mut a653rs::prelude::StartContext<Hypervisor>){
+                    #resolve
                     let ctx = start::Context::new(ctx);
-                    #cold_start (ctx)
+                    #cold_start (ctx);
                 }
 
                 fn warm_start(&self, ctx: &mut a653rs::prelude::StartContext<Hypervisor>){
+                    #resolve
                     let ctx = start::Context::new(ctx);
-                    #warm_start (ctx)
+                    #warm_start (ctx);
+                }
+
+                fn check_required(&self) -> Result<(), StartError> {
+                    #(#checks)*
+                    Ok(())
                 }
             }
         }
     }
 }
+
+fn gen_duration(duration: Duration) -> Expr {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    parse_quote!(core::time::Duration::new(#secs, #nanos))
+}
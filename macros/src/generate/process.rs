@@ -1,28 +1,170 @@
+use std::time::Duration;
+
 use quote::format_ident;
 use syn::{
-    parse_quote, Expr, ItemConst, ItemFn, ItemImpl, ItemMod, ItemStatic, LitByteStr, LitStr, Path,
+    parse_quote, Expr, ItemConst, ItemFn, ItemImpl, ItemMod, ItemStatic, LitByteStr, Path, Stmt,
 };
 
 use super::context::Context;
-use crate::parse::process::Process;
+use crate::generate::util::gen_name;
+use crate::parse::process::{Body, Process, SystemTime, WaitError};
 
 impl Process {
     fn gen_wrapper_fn(&self) -> ItemFn {
         let ident = &self.ident;
+        let (lookup, value, instance): (Option<Stmt>, Expr, Expr) = match self.instances {
+            Some(_) => (
+                Some(parse_quote! {
+                    let Some(instance) = instance() else {
+                        return missing_resources();
+                    };
+                }),
+                parse_quote!(VALUE[instance]),
+                parse_quote!(instance),
+            ),
+            None => (None, parse_quote!(VALUE), parse_quote!(0)),
+        };
+        let call: Vec<Stmt> = match self.budget {
+            Some(_) => parse_quote! {
+                let Ok(mut ctx) = Context::new(proc_self, #instance) else {
+                    return missing_resources();
+                };
+                ctx.execution_time = execution_time;
+                super:: #ident(ctx);
+            },
+            None => parse_quote! {
+                let Ok(ctx) = Context::new(proc_self, #instance) else {
+                    return missing_resources();
+                };
+                super:: #ident(ctx);
+            },
+        };
+        let body = match self.body {
+            Body::Once => call,
+            Body::Step => self.gen_step_loop(call),
+        };
+        parse_quote! {
+            pub(super) extern "C" fn wrapper() {
+                #lookup
+                // Only fails if the partition was started without its required resources
+                let Ok(proc_self) = #value.get() else {
+                    return missing_resources();
+                };
+                #(#body)*
+            }
+        }
+    }
+
+    /// Looks up the instance of the running process by its process index
+    fn gen_instance_fn(&self) -> Option<ItemFn> {
+        self.instances?;
+        Some(parse_quote! {
+            fn instance() -> Option<usize> {
+                let index = Process::<Hypervisor>::get_my_index().ok()?;
+                INDICES.iter().position(|i| i.get() == Ok(&index))
+            }
+        })
+    }
+
+    /// Invokes the process function once per period
+    fn gen_step_loop(&self, call: Vec<Stmt>) -> Vec<Stmt> {
+        let wait: Stmt = match self.wait_error {
+            WaitError::Report => parse_quote! {
+                if <Hypervisor as ApexTimeP4Ext>::periodic_wait().is_err() {
+                    <Hypervisor as ApexErrorP4Ext>::report_application_message(WAIT_FAILED).ok();
+                    Process::<Hypervisor>::stop_self();
+                }
+            },
+            WaitError::Panic => parse_quote! {
+                if let Err(e) = <Hypervisor as ApexTimeP4Ext>::periodic_wait() {
+                    panic!("periodic_wait failed: {e:?}");
+                }
+            },
+            WaitError::Ignore => {
+                let period: Duration = match self.period {
+                    SystemTime::Normal(period) => period.into(),
+                    SystemTime::Infinite => Duration::ZERO,
+                };
+                let secs = period.as_secs();
+                let nanos = period.subsec_nanos();
+                parse_quote! {
+                    // Wait for one period anyway, so the function is not invoked in a busy loop
+                    if <Hypervisor as ApexTimeP4Ext>::periodic_wait().is_err()
+                        && <Hypervisor as ApexTimeP1Ext>::timed_wait(
+                            core::time::Duration::new( #secs , #nanos ),
+                        )
+                        .is_err()
+                    {
+                        Process::<Hypervisor>::stop_self();
+                    }
+                }
+            }
+            WaitError::Stop => parse_quote! {
+                if <Hypervisor as ApexTimeP4Ext>::periodic_wait().is_err() {
+                    Process::<Hypervisor>::stop_self();
+                }
+            },
+        };
+        let Some(_) = self.budget else {
+            return parse_quote! {
+                loop {
+                    #(#call)*
+                    #wait
+                }
+            };
+        };
         parse_quote! {
-            pub(super) extern "C" fn wrapper () {
-                let proc_self;
-                unsafe {
-                    proc_self = VALUE.as_ref().unwrap();
+            let mut execution_time = None;
+            loop {
+                let start = <Hypervisor as ApexTimeP4Ext>::now();
+                #(#call)*
+                // Without the current time, the activation can not be measured
+                execution_time = start.and_then(|s| s.elapsed::<Hypervisor>()).ok();
+                if matches!(execution_time, Some(e) if e > BUDGET) {
+                    <Hypervisor as ApexErrorP4Ext>::raise_application_error(BUDGET_EXCEEDED).ok();
                 }
-                let ctx = Context::new(proc_self);
-                super:: #ident(ctx)
+                #wait
             }
         }
     }
 
     pub fn gen_create_fn(&self) -> ItemImpl {
         let create_ident = format_ident!("create_{}", self.ident);
+        let core_affinity: Option<Stmt> = self.core_affinity.map(
+            |_| parse_quote!(self.ctx.initialize_process_core_affinity(process, CORE_AFFINITY)?;),
+        );
+        let start: Option<Stmt> = match self.delayed_start {
+            Some(_) => Some(parse_quote!(process.delayed_start(DELAYED_START)?;)),
+            None if self.autostart => Some(parse_quote!(process.start()?;)),
+            None => None,
+        };
+        if self.instances.is_some() {
+            return parse_quote! {
+                impl<'a> super:: StartContext<'a, Hypervisor> {
+                    pub fn #create_ident<'b>(&'b mut self, instance: usize) -> Result<&'b Process::<Hypervisor>, Error>{
+                        if instance >= INSTANCES {
+                            return Err(Error::InvalidParam);
+                        }
+                        let attr =  ProcessAttribute {
+                            period: PERIOD,
+                            time_capacity: TIME_CAPACITY,
+                            entry_point: wrapper,
+                            stack_size: STACK_SIZE,
+                            base_priority: BASE_PRIORITY,
+                            deadline: DEADLINE,
+                            name: NAMES[instance].clone(),
+                        };
+                        let process = self.ctx.create_process(attr)?;
+                        self.processes += 1;
+                        INDICES[instance].set(self.ctx, self.processes)?;
+                        let process = VALUE[instance].set(self.ctx, process)?;
+                        #core_affinity
+                        #start
+                        Ok(process)
+                    }
+                }
+            };
+        }
         parse_quote! {
             impl<'a> super:: StartContext<'a, Hypervisor> {
                 pub fn #create_ident<'b>(&'b mut self) -> Result<&'b Process::<Hypervisor>, Error>{
@@ -37,53 +179,103 @@ impl Process {
                         name: NAME,
                     };
                     let process = self.ctx.create_process(attr)?;
-                    // This is safe because during cold/warm start only one thread works
-                    unsafe {
-                        VALUE = Some( process );
-                        Ok(VALUE.as_ref().unwrap())
-                    }
+                    self.processes += 1;
+                    let process = VALUE.set(self.ctx, process)?;
+                    #core_affinity
+                    #start
+                    Ok(process)
                 }
             }
         }
     }
 
-    pub fn gen_static_value(&self) -> ItemStatic {
+    pub fn gen_static_values(&self) -> Vec<ItemStatic> {
+        if self.instances.is_some() {
+            return vec![
+                parse_quote! {
+                    pub(super) static VALUE : [StartCell<Process::<Hypervisor>>; INSTANCES] = [const { StartCell::new() }; INSTANCES];
+                },
+                parse_quote! {
+                    /// Process index of each instance, see [Process::get_my_index]
+                    pub(super) static INDICES : [StartCell<ProcessIndex>; INSTANCES] = [const { StartCell::new() }; INSTANCES];
+                },
+            ];
+        }
+        vec![parse_quote! {
+            pub(super) static VALUE : StartCell<Process::<Hypervisor>> = StartCell::new();
+        }]
+    }
+
+    /// Entry of the partition manifest, built from the consts of the process mod
+    pub fn gen_manifest(&self) -> Expr {
+        let ident = &self.ident;
+        let names: Expr = match self.instances {
+            Some(_) => parse_quote!(&#ident::NAMES),
+            None => parse_quote!(&[#ident::NAME]),
+        };
         parse_quote! {
-            pub(super) static mut VALUE : Option<Process::<Hypervisor>> = None;
+            ProcessManifest {
+                names: #names,
+                period: #ident::PERIOD,
+                time_capacity: #ident::TIME_CAPACITY,
+                base_priority: #ident::BASE_PRIORITY,
+                stack_size: #ident::STACK_SIZE,
+                deadline: #ident::DEADLINE,
+            }
         }
     }
 
     pub fn gen_consts(&self) -> syn::Result<Vec<ItemConst>> {
-        Ok(vec![
-            self.gen_const_name()?,
+        let mut consts = match self.instances {
+            Some(instances) => vec![
+                Self::gen_const_instances(instances),
+                self.gen_const_names()?,
+            ],
+            None => vec![self.gen_const_name()?],
+        };
+        consts.extend([
             self.gen_const_time_capacity(),
             self.gen_const_period(),
             self.gen_const_stack_size(),
             self.gen_const_base_priority(),
             self.gen_const_deadline(),
-        ])
+        ]);
+        consts.extend(self.gen_const_core_affinity());
+        consts.extend(self.gen_const_delayed_start());
+        consts.extend(self.gen_const_budget());
+        consts.extend(self.gen_const_wait_failed());
+        Ok(consts)
+    }
+
+    fn gen_name(&self, name: &str) -> syn::Result<Expr> {
+        gen_name(name, &self.ident)
     }
 
     pub fn gen_const_name(&self) -> syn::Result<ItemConst> {
-        const LEN: usize = 32;
-        let name = self.name.to_string();
-        let len = name.bytes().len();
-        if len > LEN {
-            return Err(syn::Error::new_spanned(
-                self.name.clone(),
-                format!("max name length is {LEN} bytes"),
-            ));
-        }
-        let name = &format!("{name}{:\0<1$}", "", LEN - len);
-        let lit_name: LitStr = parse_quote!(#name);
-        let name = LitByteStr::new(name.as_bytes(), lit_name.span());
+        let name = self.gen_name(&self.name.to_string())?;
+        Ok(parse_quote! {
+             pub(super) const NAME: Name = #name;
+        })
+    }
 
+    /// Instance names are the process name suffixed with `_<instance>`
+    pub fn gen_const_names(&self) -> syn::Result<ItemConst> {
+        let names = self
+            .apex_names()
+            .iter()
+            .map(|name| self.gen_name(name))
+            .collect::<syn::Result<Vec<_>>>()?;
         Ok(parse_quote! {
-             pub(super) const NAME: Name =
-                Name::new( * #name );
+             pub(super) const NAMES: [Name; INSTANCES] = [ #(#names),* ];
         })
     }
 
+    pub fn gen_const_instances(instances: usize) -> ItemConst {
+        parse_quote! {
+             pub(super) const INSTANCES: usize = #instances ;
+        }
+    }
+
     pub fn gen_const_time_capacity(&self) -> ItemConst {
         let time_capacity: Expr = self.time_capacity.into();
         parse_quote! {
@@ -119,11 +311,57 @@ impl Process {
         }
     }
 
+    pub fn gen_const_core_affinity(&self) -> Option<ItemConst> {
+        let core_affinity = self.core_affinity?;
+        Some(parse_quote! {
+             pub(super) const CORE_AFFINITY: ProcessorCoreId = #core_affinity ;
+        })
+    }
+
+    pub fn gen_const_delayed_start(&self) -> Option<ItemConst> {
+        let delayed_start: Expr = SystemTime::Normal(self.delayed_start?).into();
+        Some(parse_quote! {
+             pub(super) const DELAYED_START: SystemTime = #delayed_start ;
+        })
+    }
+
+    pub fn gen_const_budget(&self) -> Vec<ItemConst> {
+        let Some(budget) = self.budget else {
+            return vec![];
+        };
+        let budget: Duration = budget.into();
+        let secs = budget.as_secs();
+        let nanos = budget.subsec_nanos();
+        let msg = format!("budget of process {} exceeded", self.name);
+        let msg = LitByteStr::new(msg.as_bytes(), self.ident.span());
+        vec![
+            parse_quote! {
+                 pub(super) const BUDGET: core::time::Duration =
+                     core::time::Duration::new( #secs , #nanos );
+            },
+            parse_quote! {
+                 pub(super) const BUDGET_EXCEEDED: &[ApexByte] = #msg;
+            },
+        ]
+    }
+
+    pub fn gen_const_wait_failed(&self) -> Option<ItemConst> {
+        if self.body != Body::Step || self.wait_error != WaitError::Report {
+            return None;
+        }
+        let msg = format!("periodic_wait of process {} failed", self.name);
+        let msg = LitByteStr::new(msg.as_bytes(), self.ident.span());
+        Some(parse_quote! {
+             pub(super) const WAIT_FAILED: &[ApexByte] = #msg;
+        })
+    }
+
     pub fn gen_process_mod(&self) -> syn::Result<ItemMod> {
         let ident = &self.ident;
         let wrapper = self.gen_wrapper_fn();
+        let instance_fn = self.gen_instance_fn();
         let consts = self.gen_consts()?;
-        let static_value = self.gen_static_value();
+        let static_values = self.gen_static_values();
         let create_fn = self.gen_create_fn();
         let context_ident = Context::from_process(self).get_context_ident();
         Ok(parse_quote! {
@@ -134,9 +372,20 @@ impl Process {
                 pub(super) type Context<'a> = super:: #context_ident <'a, Hypervisor> ;
 
                 #wrapper
+                #instance_fn
+
+                /// Raises an application error and stops this process
+                fn missing_resources() {
+                    <Hypervisor as ApexErrorP4Ext>::raise_application_error(
+                        b"process started without its required resources",
+                    )
+                    .ok();
+                    Process::<Hypervisor>::stop_self();
+                }
+
                 #create_fn
                 #(#consts)*
-                #static_value
+                #(#static_values)*
             }
         })
     }
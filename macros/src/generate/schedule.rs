@@ -0,0 +1,126 @@
+use proc_macro2::Span;
+use strum::IntoEnumIterator;
+use syn::{parse_quote, Expr, Item, ItemImpl, ItemMod, LitByteStr};
+
+use crate::generate::context::Context;
+use crate::generate::util::gen_name;
+use crate::parse::schedule::Schedule;
+use crate::partition::Partition;
+
+impl Schedule {
+    pub fn gen_name(&self) -> syn::Result<Expr> {
+        gen_name(&self.name.to_string(), &self.ident)
+    }
+
+    /// Message reported if the schedule is not configured for the module
+    pub fn gen_not_found(&self) -> LitByteStr {
+        let msg = format!("module schedule `{}` not found", self.name);
+        LitByteStr::new(msg.as_bytes(), Span::call_site())
+    }
+}
+
+impl Partition {
+    /// Enum of all declared schedules, their IDs are resolved during the partition start
+    pub fn gen_schedule_enum(&self) -> Vec<Item> {
+        let variants: Vec<_> = self.schedules.iter().map(|s| &s.ident).collect();
+        if variants.is_empty() {
+            return vec![];
+        }
+        let count = variants.len();
+        let en: Item = parse_quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Schedule {
+                #(#variants),*
+            }
+        };
+        let im: Item = parse_quote! {
+            impl Schedule {
+                pub const ALL: [Schedule; #count] = [ #(Schedule:: #variants),* ];
+
+                pub fn name(self) -> &'static Name {
+                    &schedule::NAMES[self as usize]
+                }
+
+                /// # Errors
+                /// - [Error::InvalidConfig]: the schedule was not found during the partition start
+                pub fn id(self) -> Result<ScheduleId, Error> {
+                    schedule::IDS[self as usize]
+                        .get()
+                        .copied()
+                        .map_err(|_| Error::InvalidConfig)
+                }
+
+                pub fn from_id(id: ScheduleId) -> Option<Self> {
+                    Self::ALL.into_iter().find(|s| s.id() == Ok(id))
+                }
+            }
+        };
+        vec![en, im]
+    }
+
+    pub fn gen_schedule_mod(&self) -> syn::Result<Option<ItemMod>> {
+        if self.schedules.is_empty() {
+            return Ok(None);
+        }
+        let count = self.schedules.len();
+        let names = self
+            .schedules
+            .iter()
+            .map(Schedule::gen_name)
+            .collect::<syn::Result<Vec<_>>>()?;
+        let not_found = self.schedules.iter().map(Schedule::gen_not_found);
+        Ok(Some(parse_quote! {
+            mod schedule {
+                use super::Hypervisor;
+                use a653rs::prelude::*;
+
+                pub(super) static NAMES: [Name; #count] = [ #(#names),* ];
+                pub(super) static IDS: [StartCell<ScheduleId>; #count] =
+                    [const { StartCell::new() }; #count];
+                static NOT_FOUND: [&[ApexByte]; #count] = [ #(#not_found),* ];
+
+                /// Schedules which are not configured for the module are reported and left unresolved
+                pub(super) fn resolve(ctx: &mut StartContext<Hypervisor>) {
+                    for ((id, name), not_found) in IDS.iter().zip(NAMES.iter()).zip(NOT_FOUND) {
+                        match <Hypervisor as ApexScheduleP2Ext>::get_module_schedule_id(name.clone().into()) {
+                            Ok(schedule_id) => {
+                                // During a warm start the schedule may already be resolved
+                                id.set(ctx, schedule_id).ok();
+                            }
+                            // Reporting is best effort, switching to the schedule fails later on anyway
+                            Err(_) => {
+                                <Hypervisor as ApexErrorP4Ext>::report_application_message(not_found).ok();
+                            }
+                        }
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Schedule functions using the declared `Schedule` enum
+    pub fn gen_schedule_impls(&self) -> impl Iterator<Item = ItemImpl> + '_ {
+        Context::iter()
+            .filter(|_| !self.schedules.is_empty())
+            .map(|c| {
+                let ctx = c.get_context_ident();
+                let switch = (!matches!(c, Context::Start)).then(|| -> syn::ItemFn {
+                    parse_quote! {
+                        pub fn switch_schedule(&self, schedule: Schedule) -> Result<(), Error> {
+                            <Hypervisor as ApexScheduleP2Ext>::set_module_schedule(schedule.id()?)
+                        }
+                    }
+                });
+                parse_quote! {
+                    impl<'a, Hypervisor: ApexScheduleP2Ext> #ctx <'a, Hypervisor> {
+                        pub fn schedule_status(&self) -> Result<ScheduleStatus<Option<Schedule>>, Error> {
+                            Ok(<Hypervisor as ApexScheduleP2Ext>::get_module_schedule_status()?
+                                .map(Schedule::from_id))
+                        }
+
+                        #switch
+                    }
+                }
+            })
+    }
+}
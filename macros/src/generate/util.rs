@@ -1,10 +1,11 @@
 use std::time::Duration;
 
-use quote::format_ident;
-use syn::{parse_quote, Expr, Path};
+use quote::{format_ident, ToTokens};
+use syn::{parse_quote, Expr, LitByteStr, LitStr, Path};
 
 use crate::parse::channel::QueuingDiscipline;
 use crate::parse::process::{Deadline, SystemTime};
+use crate::parse::util::MAX_NAME_LENGTH;
 
 impl From<Deadline> for Path {
     fn from(d: Deadline) -> Self {
@@ -35,3 +36,20 @@ impl From<QueuingDiscipline> for Path {
         parse_quote!(QueuingDiscipline:: #var)
     }
 }
+
+/// Nul padded `Name` constructor, `tokens` are used for the span of the error
+pub fn gen_name(name: &str, tokens: impl ToTokens) -> syn::Result<Expr> {
+    const LEN: usize = MAX_NAME_LENGTH;
+    let len = name.len();
+    if len > LEN {
+        return Err(syn::Error::new_spanned(
+            tokens,
+            format!("max name length is {LEN} bytes"),
+        ));
+    }
+    let name = &format!("{name}{:\0<1$}", "", LEN - len);
+    let lit_name: LitStr = parse_quote!(#name);
+    let name = LitByteStr::new(name.as_bytes(), lit_name.span());
+
+    Ok(parse_quote!(Name::new( * #name )))
+}
@@ -1,7 +1,8 @@
 #![deny(rustdoc::broken_intra_doc_links)]
+use parse::partition::PartitionArgs;
 use partition::Partition;
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemMod, TypePath};
+use syn::{parse_macro_input, ItemMod};
 
 mod generate;
 mod parse;
@@ -87,9 +88,20 @@ mod partition;
 ///
 /// ## Requirements
 ///
-/// #### #[partition(HYPERVISOR)]
+/// #### #[partition(HYPERVISOR, IDLE)]
 ///
 /// - *HYPERVISOR*: the full path to the used hypervisor
+/// - *IDLE*: what the initialization process does after the start (optional)
+///   - "Spin": busy loop, like `run()`
+///   - "StopSelf": stop the initialization process
+///   - time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime): repeatedly wait for this time
+///   - path to a hypervisor specific `fn()` for yielding, called repeatedly
+///
+/// With IDLE, a `Partition::run_idle()` and a `Partition::try_run_idle(policy)` using this policy are generated and `a653rs::prelude::IdlePolicy` is available as `IDLE`.
+/// For a time, HYPERVISOR must then also implement `a653rs::bindings::ApexTimeP1`,
+/// for "StopSelf" `a653rs::bindings::ApexProcessP1`.
+/// As ARINC653 defines waiting and stopping for processes only, both additionally require the hypervisor to support
+/// them for the initialization process. Otherwise `run_idle` returns the error of the first failed attempt.
 ///
 /// #### Module
 /// - [`start(cold)`] and [`start(cold)`]
@@ -102,6 +114,14 @@ mod partition;
 ///
 /// - The module name can be anything
 ///
+/// ## Names
+///
+/// - Names of processes, channels, schedules and memory blocks must be ASCII and at most 32 characters long
+///   - The names of instanced processes include their `_<instance>` suffix
+/// - Processes and channels share a single name space, so their names must be unique across both
+/// - The generated modules of channels, memory blocks, processes and the error handler must not collide
+///   - `start` and `schedule` are reserved
+///
 /// ## Example
 /// ```no_run
 /// use a653rs::prelude::PartitionExt;
@@ -123,11 +143,122 @@ mod partition;
 /// }
 /// ```
 ///
+/// ## Idle Example
+/// ```no_run
+/// use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+///
+/// fn main() {
+///     // Waits in steps of 50ms after the start, instead of busy looping
+///     let Err(e) = example::Partition.run_idle();
+///     panic!("hypervisor does not support waiting after the start: {e:?}");
+/// }
+///
+/// #[partition(crate::dummy::DummyHypervisor, idle = "50ms")]
+/// mod example {
+///     #[start(cold)]
+///     fn cold_start(ctx: start::Context) { }
+///
+///     #[start(warm)]
+///     fn warm_start(ctx: start::Context) { }
+/// }
+/// ```
+///
+/// ## Manifest
+///
+/// The module also contains a `MANIFEST` constant of type `a653rs::prelude::PartitionManifest`,
+/// describing all declared channels and processes.
+/// It is accessible via `Partition::manifest()` without running the partition,
+/// i.e. for listing or verifying resources in monitoring partitions and tests.
+///
+/// ```
+/// use a653rs::prelude::{ChannelKind, QueuingDiscipline};
+/// use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+///
+/// fn main() {
+///     let manifest = example::Partition::manifest();
+///     let requests = manifest.channel(&"Requests".parse().unwrap()).unwrap();
+///     assert_eq!(requests.msg_size, 64);
+///     assert_eq!(
+///         requests.kind,
+///         ChannelKind::QueuingReceiver { nb_msgs: 8, discipline: QueuingDiscipline::Fifo }
+///     );
+///     assert!(manifest.processes.is_empty());
+/// }
+///
+/// #[partition(crate::dummy::DummyHypervisor)]
+/// mod example {
+///     #[queuing_in(msg_size = "64B", msg_count = 8, discipline = "FIFO")]
+///     struct Requests;
+///
+///     #[start(cold)]
+///     fn cold_start(ctx: start::Context) { }
+///
+///     #[start(warm)]
+///     fn warm_start(ctx: start::Context) { }
+/// }
+/// ```
+///
+/// ## Multiple Partitions
+///
+/// All generated items are local to the annotated module, so several partition modules can share one binary.
+/// Instead of `run()`, which never returns, `start_on()` of `a653rs::prelude::PartitionExt` only performs the start of a partition.
+/// This allows integration tests to host multiple partitions against a simulated module.
+///
+/// ```no_run
+/// use a653rs::prelude::{PartitionExt, StartContext};
+/// use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+///
+/// fn main() {
+///     // The simulated module is responsible for switching between the partitions
+///     let producer = producer::Partition.start_on(unsafe { StartContext::new() });
+///     let consumer = consumer::Partition.start_on(unsafe { StartContext::new() });
+/// }
+///
+/// #[partition(crate::dummy::DummyHypervisor)]
+/// mod producer {
+///     #[sampling_out(name = "Data", msg_size = "8B")]
+///     struct Data;
+///
+///     #[start(cold)]
+///     fn cold_start(mut ctx: start::Context) {
+///         ctx.create_data().unwrap();
+///     }
+///
+///     #[start(warm)]
+///     fn warm_start(ctx: start::Context) {
+///         cold_start(ctx);
+///     }
+/// }
+///
+/// #[partition(crate::dummy::DummyHypervisor)]
+/// mod consumer {
+///     #[sampling_in(name = "Data", msg_size = "8B", refresh_period = "10ms")]
+///     struct Data;
+///
+///     #[start(cold)]
+///     fn cold_start(mut ctx: start::Context) {
+///         ctx.create_data().unwrap();
+///     }
+///
+///     #[start(warm)]
+///     fn warm_start(ctx: start::Context) {
+///         cold_start(ctx);
+///     }
+/// }
+/// ```
+///
 /// ## Attributes `start(cold)` and `start(warm)`
 ///
 /// [`start(cold)`] and [`start(warm)`] are used for the start functions of the partition.
 /// Inside these functions, the `start::Context` provides simple functions
 /// for initializing processes, channels and using apex functionalities of the provided hypervisor.
+/// `start_condition()` tells the reason of the start, i.e. for initialization specific to a restart.
 ///
 /// ## Requirements
 ///
@@ -156,6 +287,9 @@ mod partition;
 ///
 /// #[start(warm)]
 /// fn warm_start(ctx: start::Context) {
+///     if ctx.start_condition() == StartCondition::HmPartitionRestart {
+///         // i.e. discard state which caused the restart
+///     }
 ///     cold_start(ctx);
 /// }
 /// # }
@@ -167,11 +301,18 @@ mod partition;
 ///
 /// Functions with either the [`periodic()`] or [`aperiodic()`] attribute use a `Context` parameter for interacting with the rest of the partition.
 /// This `Context` contains fields for all defined channels and processes as well as functions provided by the used hypervisor.
+/// These fields are `Err(Error::Uninitialized)` if the respective channel or process was not created during the start.
+/// Channels and processes marked as `required` are instead plain references,
+/// as the partition start fails with `a653rs::prelude::StartError::MissingRequired` if they were not created.
+/// `a653rs::prelude::PartitionExt::try_run` allows handling this failure instead of panicking.
 ///
 /// When a process is defined, a `create_NAME()` function is made available on the `start::Context` struct in [`start(cold)`] and [`start(warm)`].
 /// This function must be called in order to initialize the process.
 /// Also, these create functions return a reference to the process on success.
-/// For the process to be scheduled, the `start()` function must be called on this reference.
+/// Creating a resource a second time, e.g. during a warm start without reloading the partition image,
+/// fails with `Error::NoAction`.
+/// For the process to be scheduled, the `start()` function must be called on this reference,
+/// unless the process is started on creation by `autostart` or `delayed_start`.
 ///
 /// [`periodic()`]: macro@partition#attributes-periodic-and-aperiodic
 /// [`aperiodic()`]: macro@partition#attributes-periodic-and-aperiodic
@@ -181,7 +322,7 @@ mod partition;
 /// - the functions must require solely the `Context` parameter
 ///   - the module path of the `Context` is the name of the function
 ///
-/// #### #[periodic(NAME, PERIOD, TIME_CAPACITY, STACK_SIZE, BASE_PRIORITY, DEADLINE)]
+/// #### #[periodic(NAME, PERIOD, TIME_CAPACITY, STACK_SIZE, BASE_PRIORITY, DEADLINE, INSTANCES, CORE_AFFINITY, DELAYED_START, AUTOSTART, REQUIRED, BODY, WAIT_ERROR, BUDGET)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)
 /// - **PERIOD**: time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime)
@@ -192,8 +333,16 @@ mod partition;
 /// - **BASE_PRIORITY**: [i32]
 ///   - Suggested value for P4: lower than the base priority of the aperiodic process
 /// - **DEADLINE**: either "Hard" or "Soft"
+/// - **INSTANCES**: number of processes created from this function (optional)
+/// - **CORE_AFFINITY**: [i32] id of the processor core the process is bound to on creation (optional)
+/// - **DELAYED_START**: time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime), the process is started with this delay on creation (optional)
+/// - **AUTOSTART**: [bool] start the process on creation (optional, implied by **DELAYED_START**, which rejects `false`)
+/// - **REQUIRED**: [bool] the process must be created during start (optional)
+/// - **BODY**: either "Once" or "Step" (optional, defaults to "Once")
+/// - **WAIT_ERROR**: either "Report", "Panic", "Ignore" or "Stop", handling of failed `periodic_wait` calls of a "Step" body (optional, defaults to "Report")
+/// - **BUDGET**: time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime), maximum execution time of a single activation of a "Step" body (optional)
 ///
-/// #### #[aperiodic(NAME, TIME_CAPACITY, STACK_SIZE, BASE_PRIORITY, DEADLINE)]
+/// #### #[aperiodic(NAME, TIME_CAPACITY, STACK_SIZE, BASE_PRIORITY, DEADLINE, INSTANCES, CORE_AFFINITY, DELAYED_START, AUTOSTART, REQUIRED)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)  
 /// - **TIME_CAPACITY**: either "Infinite" or a time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime)
@@ -202,22 +351,98 @@ mod partition;
 /// - **BASE_PRIORITY**: [i32]
 ///   - Suggested value for P4: higher than the base priority of the periodic process
 /// - **DEADLINE**: either "Hard" or "Soft"
+/// - **INSTANCES**: number of processes created from this function (optional)
+/// - **CORE_AFFINITY**: [i32] id of the processor core the process is bound to on creation (optional)
+/// - **DELAYED_START**: time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime), the process is started with this delay on creation (optional)
+/// - **AUTOSTART**: [bool] start the process on creation (optional, implied by **DELAYED_START**, which rejects `false`)
+/// - **REQUIRED**: [bool] the process must be created during start (optional)
 ///
 /// ## Flexibility
 ///
 /// - The identifier of the functions can be anything
 /// - The identifier of the `Context` can be anything
 ///
+/// ## Instances
+///
+/// With `instances = N` the same function is used for `N` processes.
+/// Each instance is named after the process with an `_<INDEX>` suffix, i.e. `ap2_0`, `ap2_1`, ...
+/// The `create_NAME(index)` function on the `start::Context` then expects the index of the instance to create
+/// and fails with `Error::InvalidParam` for an index out of range.
+/// Inside the process, `ctx.instance` holds the index of the running instance (`0` for processes without `instances`),
+/// while the `Context` field of an instanced process is an array with one entry per instance.
+///
+/// All instances share one entry point, which looks up its instance via `ApexProcessP1::get_my_index`,
+/// hence instanced processes require the hypervisor to implement `ApexProcessP1`.
+/// ARINC653 assigns process indices in the order the processes are created, starting at `1`,
+/// so the start context records the index of every instance it creates.
+/// If an instance is started without a recorded index, it raises an application error and stops itself.
+///
+/// ## Periodic Body
+///
+/// By default the function of a periodic process is called once and is itself responsible for waiting on its period.
+/// With `body = "Step"`, the function is instead called once per period by a generated loop,
+/// which calls `periodic_wait` after each activation.
+/// If `periodic_wait` fails, the loop handles this depending on `wait_error`:
+/// - "Report": the failure is reported as an application message and the process is stopped
+/// - "Panic": the process panics, which is not suited for `panic-free` applications
+/// - "Ignore": the loop waits for one period using `timed_wait` instead, requiring `ApexTimeP1`.
+///   If this fails too, the process is stopped.
+/// - "Stop": the process is stopped
+///
+/// With a `budget`, the execution time of each activation is measured using `now`
+/// and an application error is raised if it exceeds the budget.
+/// The measured time of the previous activation is available as `ctx.execution_time`
+/// (`None` during the first activation, without a `budget` or if the time was not available).
+///
+/// ```no_run
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// # #[start(cold)]
+/// # fn cold_start(ctx: start::Context) { }
+/// # #[start(warm)]
+/// # fn warm_start(ctx: start::Context) { }
+/// #[periodic(
+///     period = "10ms",
+///     time_capacity = "Infinite",
+///     stack_size = "10KB",
+///     base_priority = 1,
+///     deadline = "Hard",
+///     body = "Step",
+///     wait_error = "Stop",
+///     budget = "2ms"
+/// )]
+/// fn control(ctx: control::Context) {
+///     // Called once per period, without any loop or periodic_wait
+///     if ctx.execution_time.is_some_and(|t| t > control::BUDGET / 2) {
+///         ctx.report_application_message(b"half of the budget used").ok();
+///     }
+/// }
+/// # }
+/// ```
+///
 /// ## Constants
 ///
 /// Inside the module of each process (named after the process in snake_case) there are some convenience constants.
 ///
 /// - NAME: `a653rs::prelude::Name`
+///   - (instanced processes) NAMES: `[a653rs::prelude::Name; INSTANCES]` instead
+/// - (instanced processes only) INSTANCES: `usize`
 /// - TIME_CAPACITY: `a653rs::prelude::SystemTime`
 /// - PERIOD: `a653rs::prelude::SystemTime`
 /// - STACK_SIZE: `a653rs::prelude::StackSize`
 /// - BASE_PRIORITY: `a653rs::prelude::Priority`
 /// - DEADLINE: `a653rs::prelude::Deadline`
+/// - (with `core_affinity` only) CORE_AFFINITY: `a653rs::prelude::ProcessorCoreId`
+/// - (with `delayed_start` only) DELAYED_START: `a653rs::prelude::SystemTime`
+/// - (with `budget` only) BUDGET: `core::time::Duration`
+/// - (with `body = "Step"` and the default `wait_error` only) WAIT_FAILED: `&[a653rs::prelude::ApexByte]`, the reported message
 ///
 /// ## Example
 /// ```no_run
@@ -238,7 +463,11 @@ mod partition;
 /// #[start(warm)]
 /// fn warm_start(mut ctx: start::Context) {
 ///     ctx.create_aperiodic2().unwrap().start().unwrap();
-///     ctx.create_periodic3().unwrap().start().unwrap();
+///     // periodic3 is started on creation
+///     ctx.create_periodic3().unwrap();
+///     for i in 0..redundant::INSTANCES {
+///         ctx.create_redundant(i).unwrap().start().unwrap();
+///     }
 /// }
 ///
 /// #[aperiodic(
@@ -258,12 +487,304 @@ mod partition;
 ///     time_capacity = "Infinite",
 ///     stack_size = "10KB",
 ///     base_priority = 1,
-///     deadline = "Hard"
+///     deadline = "Hard",
+///     delayed_start = "5ms"
 /// )]
 /// fn periodic3(ctx: periodic3::Context) {
 ///     let status = ctx.proc_self.status();
 ///     ctx.report_application_message(b"Hello World").unwrap()
 /// }
+///
+/// #[aperiodic(
+///     time_capacity = "Infinite",
+///     stack_size = "10KB",
+///     base_priority = 1,
+///     deadline = "Soft",
+///     instances = 4
+/// )]
+/// fn redundant(ctx: redundant::Context) {
+///     // Each instance may use its index for selecting e.g. its channel
+///     let instance = ctx.instance;
+///     let status = ctx.redundant[instance].unwrap().status();
+/// }
+/// # }
+/// ```
+///
+/// # Attribute `error_handler()`
+///
+/// A single function with the [`error_handler()`] attribute may be defined as the error handler of the partition.
+/// Like processes, it uses a `Context` parameter containing fields for all defined channels and processes.
+///
+/// When an error handler is defined, a `create_NAME()` and a `configure_NAME()` function are made available
+/// on the `start::Context` struct in [`start(cold)`] and [`start(warm)`].
+/// The hypervisor must implement `a653rs::prelude::ApexErrorP1Ext` for these.
+///
+/// [`error_handler()`]: macro@partition#attribute-error_handler
+///
+/// ## Requirements
+///
+/// - the function must require solely the `Context` parameter
+///   - the module path of the `Context` is the name of the function
+///
+/// #### #[error_handler(STACK_SIZE)]
+///
+/// - **STACK_SIZE**: size like ["10KB", "16kiB", "12Mb", ...](https://crates.io/crates/bytesize)
+///
+/// ## Example
+/// ```no_run
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// #[start(cold)]
+/// fn cold_start(ctx: start::Context) {
+///     warm_start(ctx);
+/// }
+///
+/// #[start(warm)]
+/// fn warm_start(mut ctx: start::Context) {
+///     ctx.create_handler().unwrap();
+/// }
+///
+/// #[error_handler(stack_size = "8KB")]
+/// fn handler(ctx: handler::Context) {
+///     let status = ctx.error_status();
+///     ctx.stop_self();
+/// }
+/// # }
+/// ```
+///
+/// # Attribute `schedule()`
+///
+/// Structs with the [`schedule()`] attribute declare module schedules, which may be switched to by this partition.
+/// The IDs of all declared schedules are resolved at the beginning of [`start(cold)`] and [`start(warm)`].
+/// Schedules which are not configured for the module are reported via `report_application_message`.
+/// A `Schedule` enum with a variant for each declared schedule is generated.
+///
+/// All contexts provide a `schedule_status()` function, identifying the current and next schedule by the `Schedule` enum.
+/// Process and error handler contexts additionally provide a `switch_schedule(Schedule)` function.
+/// Switching to a schedule which was not found during the start fails with `Error::InvalidConfig`.
+/// The hypervisor must implement `a653rs::prelude::ApexScheduleP2Ext` for these.
+///
+/// [`schedule()`]: macro@partition#attribute-schedule
+///
+/// ## Requirements
+///
+/// #### #[schedule(NAME)]
+///
+/// - **NAME**: name of the module schedule (optional)
+///
+/// ## Flexibility
+///
+/// - The identifier of the struct can be anything, it is used as the variant of the `Schedule` enum
+///
+/// ## Example
+/// ```no_run
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// #[schedule(name = "CRUISE")]
+/// struct Cruise;
+///
+/// #[schedule(name = "LANDING")]
+/// struct Landing;
+///
+/// #[start(cold)]
+/// fn cold_start(ctx: start::Context) {
+///     warm_start(ctx);
+/// }
+///
+/// #[start(warm)]
+/// fn warm_start(mut ctx: start::Context) {
+///     ctx.create_system_manager().unwrap().start().unwrap();
+/// }
+///
+/// #[aperiodic(
+///     time_capacity = "Infinite",
+///     stack_size = "10KB",
+///     base_priority = 1,
+///     deadline = "Soft"
+/// )]
+/// fn system_manager(ctx: system_manager::Context) {
+///     let status = ctx.schedule_status().unwrap();
+///     if status.current_schedule == Some(Schedule::Cruise) {
+///         ctx.switch_schedule(Schedule::Landing).unwrap();
+///     }
+/// }
+/// # }
+/// ```
+///
+/// # Attribute `memory_block()`
+///
+/// Structs with the [`memory_block()`] attribute declare memory blocks holding a value of a given type at their start.
+/// The type must implement `a653rs::prelude::Pod`.
+///
+/// For each memory block, a `create_NAME()` function is made available on the `start::Context` struct
+/// in [`start(cold)`] and [`start(warm)`].
+/// It resolves the memory block and fails with `Error::InvalidConfig`, if the memory block is too small or misaligned for the type,
+/// or if `ReadWrite` access was declared for a memory block which is not writable.
+/// The hypervisor must implement `a653rs::prelude::ApexMemoryBlockP2Ext` for this.
+///
+/// A field for each created memory block is made available on the `Context` of each process and the error handler.
+/// Depending on the declared access it is either a `a653rs::prelude::TypedMemoryBlock` or a `a653rs::prelude::TypedMemoryBlockMut`.
+/// This field is a plain reference for `required` memory blocks and a `Result` otherwise.
+///
+/// [`memory_block()`]: macro@partition#attribute-memory_block
+///
+/// ## Requirements
+///
+/// #### #[memory_block(NAME, TY, MODE, REQUIRED)]
+///
+/// - **NAME**: name of the memory block (optional)
+/// - **TY**: type of the value at the start of the memory block
+/// - **MODE**: either "Read" or "ReadWrite" (optional, defaults to "Read")
+/// - **REQUIRED**: [bool] the memory block must be created during start (optional)
+///
+/// ## Flexibility
+///
+/// - The identifier of the struct can be anything, it becomes an alias of the value type
+///
+/// ## Constants
+///
+/// Inside the module of each memory block (named after the memory block in snake_case) there are some convenience constants.
+///
+/// - NAME: `a653rs::prelude::Name`
+/// - MODE: `a653rs::prelude::MemoryBlockMode`
+///
+/// ## Example
+/// ```no_run
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// #[memory_block(name = "SHM1", ty = SharedState, mode = "ReadWrite", required = true)]
+/// struct Shared;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// pub struct SharedState {
+///     pub counter: u64,
+/// }
+///
+/// // SharedState consists only of an integer
+/// unsafe impl Pod for SharedState {}
+///
+/// #[start(cold)]
+/// fn cold_start(ctx: start::Context) {
+///     warm_start(ctx);
+/// }
+///
+/// #[start(warm)]
+/// fn warm_start(mut ctx: start::Context) {
+///     ctx.create_shared().unwrap();
+///     ctx.create_counter().unwrap().start().unwrap();
+/// }
+///
+/// #[periodic(
+///     period = "10ms",
+///     time_capacity = "Infinite",
+///     stack_size = "10KB",
+///     base_priority = 1,
+///     deadline = "Soft"
+/// )]
+/// fn counter(ctx: counter::Context) {
+///     let state = ctx.shared.read();
+///     ctx.shared.write(&SharedState {
+///         counter: state.counter + 1,
+///     });
+/// }
+/// # }
+/// ```
+///
+/// # Context functions
+///
+/// Each `Context` only provides the apex functions which are legal for its kind of function.
+/// Which of these are available further depends on the traits implemented by the used hypervisor.
+///
+/// | Function                                                            | start | periodic | aperiodic | error handler |
+/// |---------------------------------------------------------------------|:-----:|:--------:|:---------:|:-------------:|
+/// | `get_partition_status`, `set_partition_mode`                        |   x   |    x     |     x     |       x       |
+/// | `start_condition`                                                   |   x   |          |           |               |
+/// | `get_time`, `now`                                                   |   x   |    x     |     x     |       x       |
+/// | `periodic_wait`                                                     |       |    x     |           |               |
+/// | `timed_wait`, `replenish`                                           |       |    x     |     x     |               |
+/// | `report_application_message`, `raise_application_error`             |   x   |    x     |     x     |       x       |
+/// | `error_status`                                                      |       |          |           |       x       |
+/// | `get_process`, `get_my_processor_core_id`                           |   x   |    x     |     x     |       x       |
+/// | `get_my_index`, `lock_preemption`, `unlock_preemption`              |       |    x     |     x     |               |
+/// | `preemption_lock`                                                   |       |    x     |     x     |               |
+/// | `suspend_self`                                                      |       |          |     x     |               |
+/// | `stop_self`                                                         |       |    x     |     x     |       x       |
+/// | `get_module_schedule_status`, `get_module_schedule_id`              |   x   |    x     |     x     |       x       |
+/// | `set_module_schedule`                                               |       |    x     |     x     |       x       |
+/// | `schedule_status` (with [`schedule()`])                             |   x   |    x     |     x     |       x       |
+/// | `switch_schedule` (with [`schedule()`])                             |       |    x     |     x     |       x       |
+///
+/// [`schedule()`]: macro@partition#attribute-schedule
+///
+/// `set_partition_mode::<M>()` only accepts the modes of `a653rs::prelude::mode::Restart`,
+/// the partition enters `Normal` mode on its own once the start function returned.
+/// ```compile_fail
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// #[start(cold)]
+/// fn cold_start(ctx: start::Context) {
+///     // Normal mode can not be requested during the start
+///     ctx.set_partition_mode::<mode::Normal>().unwrap();
+/// }
+/// # #[start(warm)]
+/// # fn warm_start(ctx: start::Context) { }
+/// # }
+/// ```
+///
+/// Calling a function which is not legal in the current context is a compile error.
+/// ```compile_fail
+/// # use a653rs::prelude::PartitionExt;
+/// # use a653rs_macros::partition;
+/// # #[path = "../../examples/deps/dummy.rs"]
+/// # mod dummy;
+/// # fn main() {
+/// #     example::Partition.run();
+/// # }
+/// # #[partition(crate::dummy::DummyHypervisor)]
+/// # mod example {
+/// # #[start(cold)]
+/// # fn cold_start(ctx: start::Context) { }
+/// # #[start(warm)]
+/// # fn warm_start(ctx: start::Context) { }
+/// #[aperiodic(
+///     time_capacity = "Infinite",
+///     stack_size = "10KB",
+///     base_priority = 1,
+///     deadline = "Soft"
+/// )]
+/// fn aperiodic(ctx: aperiodic::Context) {
+///     // periodic_wait is only available for periodic processes
+///     ctx.periodic_wait().unwrap();
+/// }
 /// # }
 /// ```
 ///
@@ -276,6 +797,7 @@ mod partition;
 /// When a channel is defined, a `create_NAME()` function is made available on the `start::Context` struct in [`start(cold)`] and [`start(warm)`].
 /// This function must be called in order to initialize the channel.
 /// Also a field for each created channel is made available on the `Context` of each [`periodic()`] and [`aperiodic()`] process.
+/// This field is a plain reference for `required` channels and a `Result` otherwise.
 ///
 /// [`sampling_out()`]: macro@partition#attributes-sampling_out-sampling_in-queuing_out-and-queuing_in
 /// [`sampling_in()`]: macro@partition#attributes-sampling_out-sampling_in-queuing_out-and-queuing_in
@@ -284,35 +806,52 @@ mod partition;
 ///
 /// ## Requirements
 ///
-/// #### #[sampling_out(NAME, MSG_SIZE)]
+/// #### #[sampling_out(NAME, MSG_SIZE | TY, REQUIRED)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)
 /// - **MSG_SIZE**: size like ["10KB", "16kiB", "12Mb", ...](https://crates.io/crates/bytesize)
+/// - **TY**: message type implementing `a653rs::prelude::Pod`, replaces **MSG_SIZE** (optional)
+/// - **REQUIRED**: [bool] the channel must be created during start (optional)
 ///
-/// #### #[sampling_in(NAME, MSG_SIZE, REFRESH_PERIOD)]
+/// #### #[sampling_in(NAME, MSG_SIZE | TY, REFRESH_PERIOD, REQUIRED)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)  
 /// - **MSG_SIZE**: size like ["10KB", "16kiB", "12Mb", ...](https://crates.io/crates/bytesize)
+/// - **TY**: message type implementing `a653rs::prelude::Pod`, replaces **MSG_SIZE** (optional)
 /// - **REFRESH_PERIOD**: time like ["10ms", "16s", "18m", ...](https://crates.io/crates/humantime)
+/// - **REQUIRED**: [bool] the channel must be created during start (optional)
 ///
-/// #### #[queuing_out(NAME, MSG_COUNT, MSG_SIZE, DISCIPLINE)]
+/// #### #[queuing_out(NAME, MSG_COUNT, MSG_SIZE | TY, DISCIPLINE, REQUIRED)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)
 /// - **MSG_COUNT**: [u32]
 /// - **MSG_SIZE**: size like ["10KB", "16kiB", "12Mb", ...](https://crates.io/crates/bytesize)
+/// - **TY**: message type implementing `a653rs::prelude::Pod`, replaces **MSG_SIZE** (optional)
 /// - **DISCIPLINE**: either "FIFO" or "Priority"
+/// - **REQUIRED**: [bool] the channel must be created during start (optional)
 ///
-/// #### #[queuing_in(NAME, MSG_COUNT, MSG_SIZE, DISCIPLINE)]
+/// #### #[queuing_in(NAME, MSG_COUNT, MSG_SIZE | TY, DISCIPLINE, REQUIRED)]
 ///
 /// - **NAME**: name used for internal apex calls (optional)  
 /// - **MSG_COUNT**: [u32]
 /// - **MSG_SIZE**: size like ["10KB", "16kiB", "12Mb", ...](https://crates.io/crates/bytesize)
+/// - **TY**: message type implementing `a653rs::prelude::Pod`, replaces **MSG_SIZE** (optional)
 /// - **DISCIPLINE**: either "FIFO" or "Priority"
+/// - **REQUIRED**: [bool] the channel must be created during start (optional)
 ///
 /// ## Flexibility
 ///
 /// - The identifier of the struct can be anything
 ///
+/// ## Typed Channels
+///
+/// Instead of a `msg_size`, a channel may specify the type of its messages with `ty`.
+/// The message size is then derived from the size of the type,
+/// which must implement `a653rs::prelude::Pod`.
+/// The channel fields on the contexts are typed ports like `a653rs::prelude::TypedSamplingPortSource`,
+/// sending and receiving values of this type instead of bytes.
+/// The identifier of the struct becomes an alias of the message type.
+///
 /// ## Constants
 ///
 /// Inside the module of each channel (named after the channel in snake_case) there are some convenience constants.
@@ -344,9 +883,23 @@ mod partition;
 /// #[queuing_out(msg_count = 20, msg_size = "12KB", discipline = "FIFO")]
 /// struct Channel3;
 ///
-/// #[queuing_in(name = "ch_3", msg_count = 20, msg_size = "12KB", discipline = "Priority")]
+/// #[queuing_in(name = "ch_3", msg_count = 20, msg_size = "12KB", discipline = "Priority", required = true)]
 /// struct LastChannel;
 ///
+/// #[sampling_out(name = "Att", ty = AttitudeMsg)]
+/// struct Attitude;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// pub struct AttitudeMsg {
+///     pub roll: f32,
+///     pub pitch: f32,
+///     pub yaw: f32,
+/// }
+///
+/// // AttitudeMsg has no padding and consists only of floats
+/// unsafe impl Pod for AttitudeMsg {}
+///
 /// #[start(cold)]
 /// fn cold_start(ctx: start::Context) {
 ///     warm_start(ctx);
@@ -358,6 +911,7 @@ mod partition;
 ///     ctx.create_channel_two().unwrap();
 ///     ctx.create_channel_3().unwrap();
 ///     ctx.create_last_channel().unwrap();
+///     ctx.create_attitude().unwrap();
 /// }
 /// # }
 /// ```
@@ -367,10 +921,7 @@ mod partition;
 #[proc_macro_attribute]
 pub fn partition(args: TokenStream, input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemMod);
-    // Right now we only expect the Identifier of the used Hypervisor here
-    let args = parse_macro_input!(args as TypePath);
-
-    // TODO allow only for a single partition per project
+    let args = parse_macro_input!(args as PartitionArgs);
 
     Partition::expand_partition(input, args)
         .unwrap_or_else(syn::Error::into_compile_error)
@@ -1,43 +1,17 @@
-use std::fmt::Display;
 use std::str::FromStr;
 use std::time::Duration;
 
 use darling::{FromAttributes, FromMeta};
 use proc_macro2::Ident;
-use strum::{Display, EnumString};
+use strum::{Display, EnumString, VariantNames};
 use syn::spanned::Spanned;
-use syn::{parse_quote, Attribute, Item, Type};
+use syn::{parse_quote, Item, Type};
 
 use crate::parse::util::{
-    contains_attribute, remove_attributes, MayFromAttributes, WrappedByteSize, WrappedDuration,
+    unknown_value, ApexName, MayFromAttributes, WrappedByteSize, WrappedDuration, WrappedType,
 };
 
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct ApexName(String);
-
-impl FromMeta for ApexName {
-    fn from_string(value: &str) -> darling::Result<Self> {
-        if !value.is_ascii() {
-            Err(darling::Error::custom(
-                "Port name contains not ASCII-printable characters",
-            ))
-        } else if value.len() > 16 {
-            Err(darling::Error::custom(
-                "Port name must be 16 ASCII characters or less wide",
-            ))
-        } else {
-            Ok(Self(value.to_string()))
-        }
-    }
-}
-
-impl Display for ApexName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
-    }
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumString, Display)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumString, Display, VariantNames)]
 #[strum(ascii_case_insensitive)]
 pub enum QueuingDiscipline {
     Fifo,
@@ -47,7 +21,25 @@ pub enum QueuingDiscipline {
 impl FromMeta for QueuingDiscipline {
     fn from_string(value: &str) -> darling::Result<Self> {
         QueuingDiscipline::from_str(value)
-            .map_err(|e| darling::Error::unsupported_shape(&e.to_string()))
+            .map_err(|_| unknown_value(value, QueuingDiscipline::VARIANTS))
+    }
+}
+
+/// Checks that exactly one of `msg_size` and `ty` is given
+fn check_msg_type(
+    msg_size: &Option<WrappedByteSize>,
+    ty: &Option<WrappedType>,
+) -> darling::Result<()> {
+    match (msg_size, ty) {
+        (Some(_), Some(ty)) => Err(syn::Error::new_spanned(
+            ty.as_ref(),
+            "`msg_size` and `ty` are mutually exclusive",
+        )
+        .into()),
+        (None, None) => Err(darling::Error::custom(
+            "Either `msg_size` or `ty` must be specified",
+        )),
+        _ => Ok(()),
     }
 }
 
@@ -56,17 +48,18 @@ impl FromMeta for QueuingDiscipline {
 pub struct SamplingOutProc {
     #[darling(default = "ApexName::default")]
     pub name: ApexName,
-    pub msg_size: WrappedByteSize,
+    pub msg_size: Option<WrappedByteSize>,
+    pub ty: Option<WrappedType>,
+    #[darling(default)]
+    pub required: bool,
 }
 
 impl MayFromAttributes for SamplingOutProc {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("sampling_out", attrs) {
-            return None;
-        }
-        let port = Some(Self::from_attributes(attrs.as_slice()));
-        Some(remove_attributes("sampling_out", attrs))?.ok();
-        port
+    const ATTRIBUTE: &'static str = "sampling_out";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_msg_type(&self.msg_size, &self.ty)?;
+        Ok(self)
     }
 }
 
@@ -75,18 +68,19 @@ impl MayFromAttributes for SamplingOutProc {
 pub struct SamplingInProc {
     #[darling(default = "ApexName::default")]
     pub name: ApexName,
-    pub msg_size: WrappedByteSize,
+    pub msg_size: Option<WrappedByteSize>,
+    pub ty: Option<WrappedType>,
     pub refresh_period: WrappedDuration,
+    #[darling(default)]
+    pub required: bool,
 }
 
 impl MayFromAttributes for SamplingInProc {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("sampling_in", attrs) {
-            return None;
-        }
-        let port = Some(Self::from_attributes(attrs));
-        Some(remove_attributes("sampling_in", attrs))?.ok();
-        port
+    const ATTRIBUTE: &'static str = "sampling_in";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_msg_type(&self.msg_size, &self.ty)?;
+        Ok(self)
     }
 }
 
@@ -95,19 +89,20 @@ impl MayFromAttributes for SamplingInProc {
 pub struct QueuingOutProc {
     #[darling(default = "ApexName::default")]
     pub name: ApexName,
-    pub msg_size: WrappedByteSize,
+    pub msg_size: Option<WrappedByteSize>,
+    pub ty: Option<WrappedType>,
     pub msg_count: u32,
     pub discipline: QueuingDiscipline,
+    #[darling(default)]
+    pub required: bool,
 }
 
 impl MayFromAttributes for QueuingOutProc {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("queuing_out", attrs) {
-            return None;
-        }
-        let port = Some(Self::from_attributes(attrs));
-        Some(remove_attributes("queuing_out", attrs))?.ok();
-        port
+    const ATTRIBUTE: &'static str = "queuing_out";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_msg_type(&self.msg_size, &self.ty)?;
+        Ok(self)
     }
 }
 
@@ -116,19 +111,20 @@ impl MayFromAttributes for QueuingOutProc {
 pub struct QueuingInProc {
     #[darling(default = "ApexName::default")]
     pub name: ApexName,
-    pub msg_size: WrappedByteSize,
+    pub msg_size: Option<WrappedByteSize>,
+    pub ty: Option<WrappedType>,
     pub msg_count: u32,
     pub discipline: QueuingDiscipline,
+    #[darling(default)]
+    pub required: bool,
 }
 
 impl MayFromAttributes for QueuingInProc {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("queuing_in", attrs) {
-            return None;
-        }
-        let port = Some(Self::from_attributes(attrs));
-        Some(remove_attributes("queuing_in", attrs))?.ok();
-        port
+    const ATTRIBUTE: &'static str = "queuing_in";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_msg_type(&self.msg_size, &self.ty)?;
+        Ok(self)
     }
 }
 
@@ -162,15 +158,38 @@ impl Channel {
         .clone()
     }
 
-    pub fn msg_size(&self) -> u64 {
+    /// Whether this channel must be created during the partition start
+    pub fn required(&self) -> bool {
         match self {
-            Channel::SamplingOut(_ident, ch) => ch.msg_size.bytes(),
-            Channel::SamplingIn(_ident, ch) => ch.msg_size.bytes(),
-            Channel::QueuingOut(_ident, ch) => ch.msg_size.bytes(),
-            Channel::QueuingIn(_ident, ch) => ch.msg_size.bytes(),
+            Channel::SamplingOut(_ident, ch) => ch.required,
+            Channel::SamplingIn(_ident, ch) => ch.required,
+            Channel::QueuingOut(_ident, ch) => ch.required,
+            Channel::QueuingIn(_ident, ch) => ch.required,
         }
     }
 
+    /// Message size of untyped channels
+    pub fn msg_size(&self) -> Option<u64> {
+        match self {
+            Channel::SamplingOut(_ident, ch) => ch.msg_size.as_ref(),
+            Channel::SamplingIn(_ident, ch) => ch.msg_size.as_ref(),
+            Channel::QueuingOut(_ident, ch) => ch.msg_size.as_ref(),
+            Channel::QueuingIn(_ident, ch) => ch.msg_size.as_ref(),
+        }
+        .map(WrappedByteSize::bytes)
+    }
+
+    /// Message type of typed channels
+    pub fn ty(&self) -> Option<&Type> {
+        match self {
+            Channel::SamplingOut(_ident, ch) => ch.ty.as_ref(),
+            Channel::SamplingIn(_ident, ch) => ch.ty.as_ref(),
+            Channel::QueuingOut(_ident, ch) => ch.ty.as_ref(),
+            Channel::QueuingIn(_ident, ch) => ch.ty.as_ref(),
+        }
+        .map(AsRef::as_ref)
+    }
+
     pub fn msg_count(&self) -> Option<u32> {
         match self {
             Channel::QueuingOut(_ident, ch) => Some(ch.msg_count),
@@ -194,23 +213,38 @@ impl Channel {
         None
     }
 
+    /// Type of the channel, typed channels refer to their message type by the channel ident
     pub fn typ(&self) -> Type {
+        if self.ty().is_some() {
+            let msg = self.ident();
+            return match self {
+                Channel::SamplingOut(_, _) => {
+                    parse_quote!(TypedSamplingPortSource::< #msg , Hypervisor>)
+                }
+                Channel::SamplingIn(_, _) => {
+                    parse_quote!(TypedSamplingPortDestination::< #msg , Hypervisor>)
+                }
+                Channel::QueuingOut(_, _) => {
+                    parse_quote!(TypedQueuingPortSender::< #msg , Hypervisor>)
+                }
+                Channel::QueuingIn(_, _) => {
+                    parse_quote!(TypedQueuingPortReceiver::< #msg , Hypervisor>)
+                }
+            };
+        }
+        let size = self.msg_size().unwrap_or_default() as u32;
         match self {
-            Channel::SamplingOut(_, s) => {
-                let size = s.msg_size.bytes() as u32;
+            Channel::SamplingOut(_, _) => {
                 parse_quote!(ConstSamplingPortSource::< #size , Hypervisor>)
             }
-            Channel::SamplingIn(_, s) => {
-                let size = s.msg_size.bytes() as u32;
+            Channel::SamplingIn(_, _) => {
                 parse_quote!(ConstSamplingPortDestination::< #size , Hypervisor>)
             }
             Channel::QueuingOut(_, q) => {
-                let size = q.msg_size.bytes() as u32;
                 let count = q.msg_count;
                 parse_quote!(ConstQueuingPortSender::< #size , #count , Hypervisor>)
             }
             Channel::QueuingIn(_, q) => {
-                let size = q.msg_size.bytes() as u32;
                 let count = q.msg_count;
                 parse_quote!(ConstQueuingPortReceiver::< #size , #count , Hypervisor>)
             }
@@ -226,7 +260,7 @@ impl Channel {
                     let mut vec: Vec<Option<darling::Result<Channel>>> = vec![
                         SamplingOutProc::may_from_attributes(&mut item.attrs).map(|x| {
                             let mut x = x?;
-                            if x.name.0.is_empty() {
+                            if x.name.is_empty() {
                                 let alt_name: ApexName =
                                     FromMeta::from_string(&item.ident.to_string())?;
                                 x.name = alt_name;
@@ -235,7 +269,7 @@ impl Channel {
                         }),
                         SamplingInProc::may_from_attributes(&mut item.attrs).map(|x| {
                             let mut x = x?;
-                            if x.name.0.is_empty() {
+                            if x.name.is_empty() {
                                 let alt_name: ApexName =
                                     FromMeta::from_string(&item.ident.to_string())?;
 
@@ -245,7 +279,7 @@ impl Channel {
                         }),
                         QueuingOutProc::may_from_attributes(&mut item.attrs).map(|x| {
                             let mut x = x?;
-                            if x.name.0.is_empty() {
+                            if x.name.is_empty() {
                                 let alt_name: ApexName =
                                     FromMeta::from_string(&item.ident.to_string())?;
                                 x.name = alt_name;
@@ -254,7 +288,7 @@ impl Channel {
                         }),
                         QueuingInProc::may_from_attributes(&mut item.attrs).map(|x| {
                             let mut x = x?;
-                            if x.name.0.is_empty() {
+                            if x.name.is_empty() {
                                 let alt_name: ApexName =
                                     FromMeta::from_string(&item.ident.to_string())?;
                                 x.name = alt_name;
@@ -291,28 +325,34 @@ impl Channel {
 
 #[cfg(test)]
 mod tests {
-    use darling::FromMeta;
+    use syn::{parse_quote, Item};
 
-    use super::ApexName;
+    use super::Channel;
 
     #[test]
-    fn long_port_name() {
-        let port = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
-        let port: darling::Result<ApexName> = FromMeta::from_string(port);
-        assert!(port.is_err());
+    fn typed_channel() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[sampling_out(ty = [u32; 4])]
+            struct Typed;
+        }];
+        let channel = Channel::from_content(&mut items).unwrap();
+        assert_eq!(channel[0].ty(), Some(&parse_quote!([u32; 4])));
+        assert_eq!(channel[0].msg_size(), None);
+        assert!(items.is_empty());
     }
 
     #[test]
-    fn ascii_printable_name() {
-        let port = "[AA!-=-14**\\";
-        let port: darling::Result<ApexName> = FromMeta::from_string(port);
-        assert!(port.is_ok());
-    }
+    fn ambiguous_message_size() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[queuing_out(msg_size = "4B", ty = u32, msg_count = 2, discipline = "FIFO")]
+            struct Both;
+        }];
+        assert!(Channel::from_content(&mut items).is_err());
 
-    #[test]
-    fn non_ascii_printable_name() {
-        let port = "\u{7FFF}";
-        let port: darling::Result<ApexName> = FromMeta::from_string(port);
-        assert!(port.is_err());
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[queuing_out(msg_count = 2, discipline = "FIFO")]
+            struct Neither;
+        }];
+        assert!(Channel::from_content(&mut items).is_err());
     }
 }
@@ -0,0 +1,57 @@
+use bytesize::ByteSize;
+use darling::FromAttributes;
+use syn::spanned::Spanned;
+use syn::{Ident, Item};
+
+use crate::parse::util::{
+    no_return_type, single_function_argument, MayFromAttributes, WrappedByteSize,
+};
+
+#[derive(Debug, Clone, FromAttributes)]
+#[darling(attributes(error_handler))]
+struct ErrorHandlerAttr {
+    stack_size: WrappedByteSize,
+}
+
+impl MayFromAttributes for ErrorHandlerAttr {
+    const ATTRIBUTE: &'static str = "error_handler";
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorHandler {
+    /// Used for identifying the error handler in the start context and its `mod`
+    pub ident: Ident,
+    pub stack_size: ByteSize,
+}
+
+impl ErrorHandler {
+    pub fn from_content(items: &mut [Item]) -> syn::Result<Option<ErrorHandler>> {
+        let mut handler: Option<(ErrorHandler, Item)> = None;
+        for item in items.iter_mut().filter_map(|item| match item {
+            Item::Fn(f) => Some(f),
+            _ => None,
+        }) {
+            let Some(attr) = ErrorHandlerAttr::may_from_attributes(&mut item.attrs) else {
+                continue;
+            };
+            let attr = attr.map_err(|e| syn::Error::from(e.with_span(&item.span())))?;
+
+            single_function_argument(
+                &syn::Type::Path(syn::parse_str(&format!("{}::Context", item.sig.ident)).unwrap()),
+                &item.sig,
+            )?;
+            no_return_type("ErrorHandler", &item.sig.output)?;
+
+            let current = ErrorHandler {
+                ident: item.sig.ident.clone(),
+                stack_size: attr.stack_size.into(),
+            };
+            if let Some((_, leftover)) = handler.replace((current, Item::Fn(item.clone()))) {
+                let mut err = syn::Error::new(item.span(), "ErrorHandler already defined");
+                err.combine(syn::Error::new(leftover.span(), "First definition here"));
+                return Err(err);
+            }
+        }
+        Ok(handler.map(|(h, _)| h))
+    }
+}
@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use darling::{FromAttributes, FromMeta};
+use proc_macro2::Ident;
+use strum::{Display, EnumString, VariantNames};
+use syn::spanned::Spanned;
+use syn::{Item, Type};
+
+use crate::parse::util::{unknown_value, ApexName, MayFromAttributes, WrappedType};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, EnumString, Display, VariantNames)]
+#[strum(ascii_case_insensitive)]
+pub enum MemoryBlockMode {
+    #[default]
+    Read,
+    ReadWrite,
+}
+
+impl FromMeta for MemoryBlockMode {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        MemoryBlockMode::from_str(value)
+            .map_err(|_| unknown_value(value, MemoryBlockMode::VARIANTS))
+    }
+}
+
+#[derive(Debug, Clone, FromAttributes)]
+#[darling(attributes(memory_block))]
+struct MemoryBlockAttr {
+    #[darling(default = "ApexName::default")]
+    name: ApexName,
+    ty: WrappedType,
+    #[darling(default)]
+    mode: MemoryBlockMode,
+    #[darling(default)]
+    required: bool,
+}
+
+impl MayFromAttributes for MemoryBlockAttr {
+    const ATTRIBUTE: &'static str = "memory_block";
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryBlock {
+    /// Used for identifying this memory block in contexts and its `mod`
+    pub ident: Ident,
+    pub name: ApexName,
+    /// Type of the value at the start of the memory block
+    pub ty: Type,
+    pub mode: MemoryBlockMode,
+    pub required: bool,
+}
+
+impl MemoryBlock {
+    pub fn from_content(items: &mut Vec<Item>) -> syn::Result<Vec<MemoryBlock>> {
+        let mut blocks = vec![];
+        *items = items
+            .drain(..)
+            .filter_map(|item| match item {
+                Item::Struct(mut item) => {
+                    let Some(attr) = MemoryBlockAttr::may_from_attributes(&mut item.attrs) else {
+                        return Some(Ok(Item::Struct(item)));
+                    };
+                    let block = attr
+                        .and_then(|mut attr| {
+                            if attr.name.is_empty() {
+                                attr.name = FromMeta::from_string(&item.ident.to_string())?;
+                            }
+                            Ok(MemoryBlock {
+                                ident: item.ident.clone(),
+                                name: attr.name,
+                                ty: attr.ty.into(),
+                                mode: attr.mode,
+                                required: attr.required,
+                            })
+                        })
+                        .map_err(|e| syn::Error::from(e.with_span(&item.span())));
+                    match block {
+                        Ok(block) => {
+                            blocks.push(block);
+                            None
+                        }
+                        Err(e) => Some(Err(e)),
+                    }
+                }
+                item => Some(Ok(item)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(blocks)
+    }
+}
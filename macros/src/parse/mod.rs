@@ -1,4 +1,8 @@
 pub mod channel;
+pub mod error_handler;
+pub mod memory_block;
+pub mod partition;
 pub mod process;
+pub mod schedule;
 pub mod start;
 pub mod util;
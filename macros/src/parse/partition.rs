@@ -0,0 +1,107 @@
+use darling::FromMeta;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, ExprLit, ExprPath, Ident, Lit, Path, Token, TypePath};
+
+use crate::parse::util::{unknown_value, WrappedDuration};
+
+/// Behavior of the initialization process after the partition start
+#[derive(Debug, Clone)]
+pub enum Idle {
+    Spin,
+    StopSelf,
+    TimedWait(WrappedDuration),
+    /// Path to a hypervisor specific `fn()`
+    Yield(Path),
+}
+
+impl FromMeta for Idle {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        if value.chars().any(|c| c.is_numeric()) {
+            return Ok(Self::TimedWait(WrappedDuration::from_string(value)?));
+        }
+        match value.to_lowercase().as_str() {
+            "spin" => Ok(Self::Spin),
+            "stopself" => Ok(Self::StopSelf),
+            _ => Err(unknown_value(
+                value,
+                &["Spin", "StopSelf", "<number><unit>"],
+            )),
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Self::from_string(&s.value()),
+            Expr::Path(ExprPath { path, .. }) => Ok(Self::Yield(path.clone())),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+        .map_err(|e| e.with_span(expr))
+    }
+}
+
+/// Arguments of the `#[partition(HYPERVISOR, idle = IDLE)]` attribute
+#[derive(Debug, Clone)]
+pub struct PartitionArgs {
+    pub hypervisor: TypePath,
+    pub idle: Option<Idle>,
+}
+
+impl Parse for PartitionArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let hypervisor = input.parse()?;
+        let mut idle = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value: Expr = input.parse()?;
+            match key.to_string().as_str() {
+                "idle" if idle.is_none() => idle = Some(Idle::from_expr(&value)?),
+                "idle" => return Err(syn::Error::new(key.span(), "Duplicate argument `idle`")),
+                _ => {
+                    return Err(darling::Error::unknown_field_with_alts(
+                        &key.to_string(),
+                        &["idle"],
+                    )
+                    .with_span(&key)
+                    .into())
+                }
+            }
+        }
+        Ok(PartitionArgs { hypervisor, idle })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{Idle, PartitionArgs};
+
+    #[test]
+    fn hypervisor_only() {
+        let args: PartitionArgs = parse_quote!(crate::Hypervisor);
+        assert!(args.idle.is_none());
+    }
+
+    #[test]
+    fn idle_policies() {
+        let args: PartitionArgs = parse_quote!(crate::Hypervisor, idle = "StopSelf");
+        assert!(matches!(args.idle, Some(Idle::StopSelf)));
+        let args: PartitionArgs = parse_quote!(crate::Hypervisor, idle = "10ms");
+        assert!(matches!(args.idle, Some(Idle::TimedWait(_))));
+        let args: PartitionArgs = parse_quote!(crate::Hypervisor, idle = crate::yield_now);
+        assert!(matches!(args.idle, Some(Idle::Yield(_))));
+    }
+
+    #[test]
+    fn unknown_argument() {
+        let args: syn::Result<PartitionArgs> = syn::parse_str("crate::Hypervisor, idel = \"Spin\"");
+        assert!(args.is_err());
+    }
+}
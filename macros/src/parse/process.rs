@@ -2,15 +2,14 @@ use std::str::FromStr;
 use std::string::ToString;
 
 use bytesize::ByteSize;
+use darling::util::SpannedValue;
 use darling::{FromAttributes, FromMeta};
-use quote::format_ident;
 use strum::{Display, EnumString, VariantNames};
-use syn::spanned::Spanned;
-use syn::{Attribute, Ident, Item};
+use syn::{Ident, Item};
 
 use crate::parse::util::{
-    contains_attribute, no_return_type, remove_attributes, single_function_argument,
-    MayFromAttributes, WrappedByteSize, WrappedDuration,
+    no_return_type, single_function_argument, unknown_value, ApexName, MayFromAttributes,
+    WrappedByteSize, WrappedDuration, MAX_NAME_LENGTH,
 };
 
 #[derive(Debug, Copy, Clone, Display)]
@@ -21,7 +20,6 @@ pub enum SystemTime {
 
 impl FromMeta for SystemTime {
     fn from_string(value: &str) -> darling::Result<Self> {
-        // TODO better suggestion for misspells
         if value.chars().any(|c| c.is_numeric()) {
             return Ok(Self::Normal(WrappedDuration::from_string(value)?));
         }
@@ -30,9 +28,32 @@ impl FromMeta for SystemTime {
             return Ok(SystemTime::Infinite);
         }
 
-        Err(darling::Error::unknown_value(
-            "Expected either 'Infinite' or '<number><unit>'",
-        ))
+        Err(unknown_value(value, &["Infinite", "<number><unit>"]))
+    }
+}
+
+/// Instanced processes consist of at least one process
+fn check_instances(instances: &Option<SpannedValue<usize>>) -> darling::Result<()> {
+    match instances {
+        Some(instances) if **instances == 0 => {
+            Err(syn::Error::new(instances.span(), "Process instances must be at least 1").into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// `delayed_start` starts the process on creation, which contradicts an explicit `autostart = false`
+fn check_autostart(
+    autostart: &Option<SpannedValue<bool>>,
+    delayed_start: &Option<WrappedDuration>,
+) -> darling::Result<()> {
+    match (autostart, delayed_start) {
+        (Some(autostart), Some(_)) if !**autostart => Err(syn::Error::new(
+            autostart.span(),
+            "`autostart = false` contradicts `delayed_start`",
+        )
+        .into()),
+        _ => Ok(()),
     }
 }
 
@@ -45,64 +66,127 @@ pub enum Deadline {
 
 impl FromMeta for Deadline {
     fn from_string(value: &str) -> darling::Result<Self> {
-        Deadline::from_str(value).map_err(|_| {
-            darling::Error::unsupported_shape_with_expected(
-                value,
-                &format!("{:?}", Deadline::VARIANTS),
-            )
-        })
+        Deadline::from_str(value).map_err(|_| unknown_value(value, Deadline::VARIANTS))
+    }
+}
+
+/// How the function of a periodic process is invoked
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Display, EnumString, VariantNames)]
+#[strum(ascii_case_insensitive)]
+pub enum Body {
+    /// Once, the function is responsible for waiting on its period itself
+    #[default]
+    Once,
+    /// Once per period by a generated loop
+    Step,
+}
+
+impl FromMeta for Body {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Body::from_str(value).map_err(|_| unknown_value(value, Body::VARIANTS))
+    }
+}
+
+/// Handling of failed `periodic_wait` calls in the generated loop of a [Body::Step]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Display, EnumString, VariantNames)]
+#[strum(ascii_case_insensitive)]
+pub enum WaitError {
+    /// Report the error as an application message and stop the process
+    #[default]
+    Report,
+    Panic,
+    /// Wait for one period using `timed_wait` instead, stop the process if that fails too
+    Ignore,
+    Stop,
+}
+
+impl FromMeta for WaitError {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        WaitError::from_str(value).map_err(|_| unknown_value(value, WaitError::VARIANTS))
     }
 }
 
 #[derive(Debug, Clone, FromAttributes)]
 #[darling(attributes(aperiodic))]
 pub struct Aperiodic {
-    #[darling(default = "String::default")]
-    name: String,
+    #[darling(default = "ApexName::default")]
+    name: ApexName,
     time_capacity: SystemTime,
     stack_size: WrappedByteSize,
     base_priority: i32,
     deadline: Deadline,
+    #[darling(default)]
+    instances: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    core_affinity: Option<i32>,
+    #[darling(default)]
+    delayed_start: Option<WrappedDuration>,
+    #[darling(default)]
+    autostart: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    required: bool,
 }
 
 impl MayFromAttributes for Aperiodic {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("aperiodic", attrs) {
-            return None;
-        }
-        let process = Some(Self::from_attributes(attrs));
-        Some(remove_attributes("aperiodic", attrs))?.ok();
-        process
+    const ATTRIBUTE: &'static str = "aperiodic";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_instances(&self.instances)?;
+        check_autostart(&self.autostart, &self.delayed_start)?;
+        Ok(self)
     }
 }
 
 #[derive(Debug, Clone, FromAttributes)]
 #[darling(attributes(periodic))]
 pub struct Periodic {
-    #[darling(default = "String::default")]
-    name: String,
+    #[darling(default = "ApexName::default")]
+    name: ApexName,
     time_capacity: SystemTime,
     period: WrappedDuration,
     stack_size: WrappedByteSize,
     base_priority: i32,
     deadline: Deadline,
+    #[darling(default)]
+    instances: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    core_affinity: Option<i32>,
+    #[darling(default)]
+    delayed_start: Option<WrappedDuration>,
+    #[darling(default)]
+    autostart: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    required: bool,
+    #[darling(default)]
+    body: Body,
+    #[darling(default)]
+    wait_error: Option<SpannedValue<WaitError>>,
+    #[darling(default)]
+    budget: Option<SpannedValue<WrappedDuration>>,
 }
 
 impl MayFromAttributes for Periodic {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
-        if !contains_attribute("periodic", attrs) {
-            return None;
+    const ATTRIBUTE: &'static str = "periodic";
+
+    fn validate(self) -> darling::Result<Self> {
+        check_instances(&self.instances)?;
+        check_autostart(&self.autostart, &self.delayed_start)?;
+        if self.body == Body::Step {
+            return Ok(self);
         }
-        let process = Some(Self::from_attributes(attrs));
-        Some(remove_attributes("periodic", attrs))?.ok();
-        process
+        let span = match (&self.wait_error, &self.budget) {
+            (Some(wait_error), _) => wait_error.span(),
+            (None, Some(budget)) => budget.span(),
+            (None, None) => return Ok(self),
+        };
+        Err(syn::Error::new(span, "`wait_error` and `budget` require `body = \"Step\"`").into())
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct Process {
     /// Solely used for the static name
-    pub name: Ident,
+    pub name: ApexName,
     /// Used for identifying this process in contexts and its `mod`
     pub ident: Ident,
     pub time_capacity: SystemTime,
@@ -110,41 +194,96 @@ pub struct Process {
     pub stack_size: ByteSize,
     pub base_priority: i32,
     pub deadline: Deadline,
+    /// Number of processes created from this function, if specified
+    pub instances: Option<usize>,
+    pub core_affinity: Option<i32>,
+    pub delayed_start: Option<WrappedDuration>,
+    /// Start the process on creation, implied by `delayed_start`
+    pub autostart: bool,
+    /// Whether this process must be created during the partition start
+    pub required: bool,
+    pub body: Body,
+    pub wait_error: WaitError,
+    /// Maximum execution time of a single activation of a [Body::Step]
+    pub budget: Option<WrappedDuration>,
 }
 
 impl Process {
-    fn from_aperiodic(ident: Ident, a: Aperiodic) -> Self {
-        let name = if a.name.is_empty() {
-            ident.clone()
-        } else {
-            format_ident!("{}", a.name, span = ident.span())
-        };
-        Process {
+    /// APEX names of all processes created from this function
+    pub fn apex_names(&self) -> Vec<String> {
+        match self.instances {
+            Some(instances) => (0..instances)
+                .map(|i| format!("{}_{i}", self.name))
+                .collect(),
+            None => vec![self.name.to_string()],
+        }
+    }
+
+    fn from_aperiodic(ident: Ident, a: Aperiodic) -> darling::Result<Self> {
+        let instances = a.instances.map(|i| *i);
+        let name = Self::name_or_ident(a.name, &ident, instances)?;
+        Ok(Process {
             time_capacity: a.time_capacity,
             period: SystemTime::Infinite,
             stack_size: a.stack_size.into(),
             base_priority: a.base_priority,
             deadline: a.deadline,
+            instances,
+            core_affinity: a.core_affinity,
+            delayed_start: a.delayed_start,
+            autostart: a.autostart.map_or(a.delayed_start.is_some(), |a| *a),
+            required: a.required,
+            body: Body::Once,
+            wait_error: WaitError::default(),
+            budget: None,
             name,
             ident,
-        }
+        })
     }
 
-    fn from_periodic(ident: Ident, p: Periodic) -> Self {
-        let name = if p.name.is_empty() {
-            ident.clone()
-        } else {
-            format_ident!("{}", p.name, span = ident.span())
-        };
-        Process {
+    fn from_periodic(ident: Ident, p: Periodic) -> darling::Result<Self> {
+        let instances = p.instances.map(|i| *i);
+        let name = Self::name_or_ident(p.name, &ident, instances)?;
+        Ok(Process {
             time_capacity: p.time_capacity,
             period: SystemTime::Normal(p.period),
             stack_size: p.stack_size.into(),
             base_priority: p.base_priority,
             deadline: p.deadline,
+            instances,
+            core_affinity: p.core_affinity,
+            delayed_start: p.delayed_start,
+            autostart: p.autostart.map_or(p.delayed_start.is_some(), |a| *a),
+            required: p.required,
+            body: p.body,
+            wait_error: p.wait_error.map(|w| *w).unwrap_or_default(),
+            budget: p.budget.map(|b| *b),
             name,
             ident,
+        })
+    }
+
+    /// Processes without a name are named after their function.
+    /// Names of instanced processes must leave room for the `_<INDEX>` suffix.
+    fn name_or_ident(
+        name: ApexName,
+        ident: &Ident,
+        instances: Option<usize>,
+    ) -> darling::Result<ApexName> {
+        let name = match name.is_empty() {
+            true => ApexName::from_string(&ident.to_string())?,
+            false => name,
+        };
+        if let Some(instances) = instances {
+            let suffix = format!("_{}", instances.saturating_sub(1));
+            if name.len() + suffix.len() > MAX_NAME_LENGTH {
+                return Err(darling::Error::custom(format!(
+                    "Name `{name}` is too long for {instances} instances, \
+                     the longest instance name `{name}{suffix}` exceeds {MAX_NAME_LENGTH} characters"
+                )));
+            }
         }
+        Ok(name)
     }
 
     pub fn from_content(items: &mut [Item]) -> syn::Result<Vec<Process>> {
@@ -155,21 +294,21 @@ impl Process {
         }) {
             let mut vec: Vec<Option<darling::Result<Process>>> = vec![
                 Aperiodic::may_from_attributes(&mut item.attrs)
-                    .map(|x| x.map(|a| Process::from_aperiodic(item.sig.ident.clone(), a))),
+                    .map(|x| x.and_then(|a| Process::from_aperiodic(item.sig.ident.clone(), a))),
                 Periodic::may_from_attributes(&mut item.attrs)
-                    .map(|x| x.map(|p| Process::from_periodic(item.sig.ident.clone(), p))),
+                    .map(|x| x.and_then(|p| Process::from_periodic(item.sig.ident.clone(), p))),
             ];
             let vec: Vec<_> = vec
                 .drain(..)
                 .flatten()
-                .map(|c| c.map_err(|e| syn::Error::from(e.with_span(&item.span()))))
+                .map(|c| c.map_err(|e| syn::Error::from(e.with_span(&item.sig.ident))))
                 .collect();
             let proc = match vec.len() {
                 0 => continue,
                 1 => Ok(vec[0].clone()?),
                 _ => Err(syn::Error::new_spanned(
-                    item.clone(),
-                    "Multiple Channels defined on same struct",
+                    item.sig.ident.clone(),
+                    "Multiple processes defined on same function",
                 )),
             }?;
 
@@ -185,3 +324,76 @@ impl Process {
         Ok(procs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Item};
+
+    use super::{Process, WaitError};
+
+    #[test]
+    fn delayed_start_implies_autostart() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[aperiodic(
+                time_capacity = "Infinite",
+                stack_size = "10KB",
+                base_priority = 1,
+                deadline = "Soft",
+                delayed_start = "1ms",
+            )]
+            fn delayed(ctx: delayed::Context) {}
+        }];
+        let procs = Process::from_content(&mut items).unwrap();
+        assert!(procs[0].autostart);
+    }
+
+    #[test]
+    fn delayed_start_without_autostart() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[aperiodic(
+                time_capacity = "Infinite",
+                stack_size = "10KB",
+                base_priority = 1,
+                deadline = "Soft",
+                delayed_start = "1ms",
+                autostart = false,
+            )]
+            fn delayed(ctx: delayed::Context) {}
+        }];
+        assert!(Process::from_content(&mut items).is_err());
+    }
+
+    #[test]
+    fn step_reports_wait_errors_by_default() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[periodic(
+                period = "10ms",
+                time_capacity = "Infinite",
+                stack_size = "10KB",
+                base_priority = 1,
+                deadline = "Soft",
+                body = "Step",
+            )]
+            fn step(ctx: step::Context) {}
+        }];
+        let procs = Process::from_content(&mut items).unwrap();
+        assert_eq!(procs[0].wait_error, WaitError::Report);
+    }
+
+    #[test]
+    fn unknown_body_suggests_variant() {
+        let mut items: Vec<Item> = vec![parse_quote! {
+            #[periodic(
+                period = "10ms",
+                time_capacity = "Infinite",
+                stack_size = "10KB",
+                base_priority = 1,
+                deadline = "Soft",
+                body = "Stp",
+            )]
+            fn step(ctx: step::Context) {}
+        }];
+        let err = Process::from_content(&mut items).unwrap_err();
+        assert!(err.to_string().contains("Did you mean `Step`?"), "{err}");
+    }
+}
@@ -0,0 +1,59 @@
+use darling::{FromAttributes, FromMeta};
+use syn::spanned::Spanned;
+use syn::{Ident, Item};
+
+use crate::parse::util::{ApexName, MayFromAttributes};
+
+#[derive(Debug, Clone, FromAttributes)]
+#[darling(attributes(schedule))]
+struct ScheduleAttr {
+    #[darling(default = "ApexName::default")]
+    name: ApexName,
+}
+
+impl MayFromAttributes for ScheduleAttr {
+    const ATTRIBUTE: &'static str = "schedule";
+}
+
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    /// Used as the variant of the generated `Schedule` enum
+    pub ident: Ident,
+    pub name: ApexName,
+}
+
+impl Schedule {
+    pub fn from_content(items: &mut Vec<Item>) -> syn::Result<Vec<Schedule>> {
+        let mut schedules = vec![];
+        *items = items
+            .drain(..)
+            .filter_map(|item| match item {
+                Item::Struct(mut item) => {
+                    let Some(attr) = ScheduleAttr::may_from_attributes(&mut item.attrs) else {
+                        return Some(Ok(Item::Struct(item)));
+                    };
+                    let schedule = attr
+                        .and_then(|mut attr| {
+                            if attr.name.is_empty() {
+                                attr.name = FromMeta::from_string(&item.ident.to_string())?;
+                            }
+                            Ok(Schedule {
+                                ident: item.ident.clone(),
+                                name: attr.name,
+                            })
+                        })
+                        .map_err(|e| syn::Error::from(e.with_span(&item.span())));
+                    match schedule {
+                        Ok(schedule) => {
+                            schedules.push(schedule);
+                            None
+                        }
+                        Err(e) => Some(Err(e)),
+                    }
+                }
+                item => Some(Ok(item)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(schedules)
+    }
+}
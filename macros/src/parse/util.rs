@@ -1,9 +1,10 @@
+use std::fmt::Display;
 use std::str::FromStr;
 use std::time::Duration;
 
 use bytesize::ByteSize;
-use darling::{FromMeta, ToTokens};
-use syn::{parse_quote, Attribute, FnArg, Ident, ReturnType, Signature};
+use darling::{FromAttributes, FromMeta, ToTokens};
+use syn::{parse_quote, Attribute, Expr, ExprLit, FnArg, Ident, Lit, ReturnType, Signature, Type};
 
 pub fn contains_attribute(attr: &str, attrs: &[Attribute]) -> bool {
     attrs
@@ -13,8 +14,92 @@ pub fn contains_attribute(attr: &str, attrs: &[Attribute]) -> bool {
         .any(|i| i.to_string().eq(attr))
 }
 
-pub trait MayFromAttributes: Sized {
-    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>>;
+/// Maximum length of APEX names, mirrors `a653rs::prelude::MAX_NAME_LENGTH`
+///
+/// The macros can not depend on a653rs, hence the value is kept in sync by a test.
+pub const MAX_NAME_LENGTH: usize = 32;
+
+pub trait MayFromAttributes: FromAttributes {
+    /// Name of the attribute, i.e. `sampling_out`
+    const ATTRIBUTE: &'static str;
+
+    /// Checks spanning multiple fields of the attribute
+    fn validate(self) -> darling::Result<Self> {
+        Ok(self)
+    }
+
+    /// Parses and removes the attribute, if present.
+    /// Errors without a more precise location point at the attribute.
+    fn may_from_attributes(attrs: &mut Vec<Attribute>) -> Option<darling::Result<Self>> {
+        if !contains_attribute(Self::ATTRIBUTE, attrs) {
+            return None;
+        }
+        let attr = attrs
+            .iter()
+            .find(|a| a.path().is_ident(Self::ATTRIBUTE))
+            .cloned();
+        let parsed = Self::from_attributes(attrs)
+            .and_then(Self::validate)
+            .map_err(|e| match &attr {
+                Some(attr) => e.with_span(attr),
+                None => e,
+            });
+        remove_attributes(Self::ATTRIBUTE, attrs).ok();
+        Some(parsed)
+    }
+}
+
+/// Error for an unknown `value`, suggesting the most similar of the `expected` values
+pub fn unknown_value(value: &str, expected: &[&str]) -> darling::Error {
+    let lower = value.to_lowercase();
+    let suggestion = expected
+        .iter()
+        .map(|e| (strsim::jaro_winkler(&lower, &e.to_lowercase()), e))
+        .filter(|(confidence, _)| *confidence > 0.8)
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+    match suggestion {
+        Some((_, s)) => {
+            darling::Error::custom(format!("Unknown value `{value}`. Did you mean `{s}`?"))
+        }
+        None => darling::Error::custom(format!(
+            "Unknown value `{value}`. Expected one of {expected:?}"
+        )),
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ApexName(String);
+
+impl ApexName {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FromMeta for ApexName {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        if !value.is_ascii() {
+            Err(darling::Error::custom(
+                "Name contains not ASCII-printable characters",
+            ))
+        } else if value.len() > MAX_NAME_LENGTH {
+            Err(darling::Error::custom(format!(
+                "Name must be {MAX_NAME_LENGTH} ASCII characters or less wide"
+            )))
+        } else {
+            Ok(Self(value.to_string()))
+        }
+    }
+}
+
+impl Display for ApexName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
 ///
@@ -107,6 +192,41 @@ impl FromMeta for WrappedDuration {
     }
 }
 
+/// Type given either as a path like `ty = Msg` or as a string like `ty = "Msg<u8>"`
+#[derive(Debug, Clone)]
+pub struct WrappedType(Type);
+
+impl From<WrappedType> for Type {
+    fn from(ty: WrappedType) -> Self {
+        ty.0
+    }
+}
+
+impl AsRef<Type> for WrappedType {
+    fn as_ref(&self) -> &Type {
+        &self.0
+    }
+}
+
+impl FromMeta for WrappedType {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Self::from_string(&s.value()),
+            expr => syn::parse2(expr.to_token_stream())
+                .map(WrappedType)
+                .map_err(|_| darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(WrappedType)
+            .map_err(|e| darling::Error::unsupported_shape(&e.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parse::util::*;
@@ -125,6 +245,11 @@ mod tests {
         assert!(!contains_attribute("non_existent_attr", &attrs));
     }
 
+    #[test]
+    fn test_max_name_length() {
+        assert_eq!(MAX_NAME_LENGTH, a653rs::prelude::MAX_NAME_LENGTH);
+    }
+
     #[test]
     fn test_no_return_type() {
         use syn::{parse_quote, ReturnType};
@@ -156,4 +281,41 @@ mod tests {
         remove_attributes("non_existent_attr", &mut attrs).unwrap();
         assert_eq!(attrs, vec![attr2]);
     }
+
+    #[test]
+    fn long_name() {
+        let name = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+        let name: darling::Result<ApexName> = FromMeta::from_string(name);
+        assert!(name.is_err());
+
+        let name = "A".repeat(MAX_NAME_LENGTH);
+        let name: darling::Result<ApexName> = FromMeta::from_string(&name);
+        assert!(name.is_ok());
+    }
+
+    #[test]
+    fn ascii_printable_name() {
+        let name = "[AA!-=-14**\\";
+        let name: darling::Result<ApexName> = FromMeta::from_string(name);
+        assert!(name.is_ok());
+    }
+
+    #[test]
+    fn non_ascii_printable_name() {
+        let name = "\u{7FFF}";
+        let name: darling::Result<ApexName> = FromMeta::from_string(name);
+        assert!(name.is_err());
+    }
+
+    #[test]
+    fn test_unknown_value() {
+        let err = unknown_value("fifo0", &["Fifo", "Priority"]).to_string();
+        assert!(err.contains("Did you mean `Fifo`?"), "{err}");
+
+        let err = unknown_value("Hardd", &["Soft", "Hard"]).to_string();
+        assert!(err.contains("Did you mean `Hard`?"), "{err}");
+
+        let err = unknown_value("xyz", &["Soft", "Hard"]).to_string();
+        assert!(!err.contains("Did you mean"), "{err}");
+    }
 }
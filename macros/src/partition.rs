@@ -1,44 +1,130 @@
-use proc_macro2::TokenStream;
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{parse_quote, Ident, Item, ItemFn, ItemMod, TypePath};
 
 use crate::generate::context::Context;
 use crate::parse::channel::Channel;
+use crate::parse::error_handler::ErrorHandler;
+use crate::parse::memory_block::MemoryBlock;
+use crate::parse::partition::{Idle, PartitionArgs};
 use crate::parse::process::Process;
+use crate::parse::schedule::Schedule;
 use crate::parse::start::Start;
 
+/// Modules generated independent of the partition content
+const RESERVED_MODULES: &[&str] = &["start", "schedule"];
+
+/// Reports the second occurrence of a name, pointing to the first one
+fn check_unique(what: &str, names: impl IntoIterator<Item = (String, Span)>) -> syn::Result<()> {
+    let mut seen: HashMap<String, Span> = HashMap::new();
+    for (name, span) in names {
+        if let Some(first) = seen.insert(name.clone(), span) {
+            let mut err = syn::Error::new(span, format!("Duplicate {what} `{name}`"));
+            err.combine(syn::Error::new(first, "First definition here"));
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct Partition {
-    pub name: Ident,
     pub hypervisor: TypePath,
+    pub idle: Option<Idle>,
     pub channel: Vec<Channel>,
     pub cold_start: ItemFn,
     pub warm_start: ItemFn,
     pub processes: Vec<Process>,
+    pub error_handler: Option<ErrorHandler>,
+    pub schedules: Vec<Schedule>,
+    pub memory_blocks: Vec<MemoryBlock>,
 }
 
 impl Partition {
-    fn from_mod(hypervisor: TypePath, input: &mut ItemMod) -> syn::Result<Partition> {
+    fn from_mod(args: PartitionArgs, input: &mut ItemMod) -> syn::Result<Partition> {
         let root_span = input.span();
         let (_, content) = input.content.as_mut().unwrap();
         let channel = Channel::from_content(content)?;
+        let schedules = Schedule::from_content(content)?;
+        let memory_blocks = MemoryBlock::from_content(content)?;
         let start = Start::from_content(&root_span, content)?;
         let processes = Process::from_content(content)?;
+        let error_handler = ErrorHandler::from_content(content)?;
 
-        Ok(Partition {
-            name: input.ident.clone(),
-            hypervisor,
+        let partition = Partition {
+            hypervisor: args.hypervisor,
+            idle: args.idle,
             channel,
             cold_start: start.cold().clone(),
             warm_start: start.warm().clone(),
             processes,
-        })
+            error_handler,
+            schedules,
+            memory_blocks,
+        };
+        partition.check_unique_names()?;
+        partition.check_unique_modules()?;
+        Ok(partition)
+    }
+
+    /// Processes and ports share the partition wide APEX name space
+    fn check_unique_names(&self) -> syn::Result<()> {
+        let processes = self.processes.iter().flat_map(|p| {
+            p.apex_names()
+                .into_iter()
+                .map(|name| (name, p.ident.span()))
+        });
+        let channels = self
+            .channel
+            .iter()
+            .map(|c| (c.name().to_string(), c.ident().span()));
+        check_unique("APEX name", channels.chain(processes))?;
+        check_unique(
+            "schedule name",
+            self.schedules
+                .iter()
+                .map(|s| (s.name.to_string(), s.ident.span())),
+        )?;
+        check_unique(
+            "memory block name",
+            self.memory_blocks
+                .iter()
+                .map(|m| (m.name.to_string(), m.ident.span())),
+        )
+    }
+
+    /// Every channel, memory block, process and the error handler get a module of their own
+    fn check_unique_modules(&self) -> syn::Result<()> {
+        let channels = self.channel.iter().map(Channel::gen_snake_ident);
+        let memory_blocks = self.memory_blocks.iter().map(MemoryBlock::gen_snake_ident);
+        let processes = self.processes.iter().map(|p| p.ident.clone());
+        let error_handler = self.error_handler.iter().map(|e| e.ident.clone());
+        let idents: Vec<Ident> = channels
+            .chain(memory_blocks)
+            .chain(processes)
+            .chain(error_handler)
+            .collect();
+        if let Some(ident) = idents
+            .iter()
+            .find(|i| RESERVED_MODULES.iter().any(|r| i == r))
+        {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("`{ident}` is reserved for a generated module"),
+            ));
+        }
+        check_unique(
+            "module",
+            idents.into_iter().map(|i| (i.to_string(), i.span())),
+        )
     }
 
-    pub fn expand_partition(mut input: ItemMod, hypervisor: TypePath) -> syn::Result<TokenStream> {
+    pub fn expand_partition(mut input: ItemMod, args: PartitionArgs) -> syn::Result<TokenStream> {
         // Parse user provided partition
-        let part = Partition::from_mod(hypervisor, &mut input)?;
+        let part = Partition::from_mod(args, &mut input)?;
         let content: &mut Vec<Item> = &mut input.content.as_mut().unwrap().1;
 
         content.push(parse_quote!(
@@ -48,8 +134,19 @@ impl Partition {
         content.push(part.gen_start_mod().into());
         content.push(part.gen_struct().into());
         content.push(part.gen_impl().into());
+        content.push(part.gen_manifest().into());
+        content.push(part.gen_manifest_impl().into());
+        content.extend(part.gen_idle().map(Into::into));
+        content.extend(part.gen_idle_impl().map(Into::into));
         content.extend(part.gen_proc_mods()?.map(Into::into));
+        content.extend(part.gen_channel_type_aliases().map(Into::into));
         content.extend(part.gen_channel_mods()?.map(Into::into));
+        content.extend(part.gen_memory_block_type_aliases().map(Into::into));
+        content.extend(part.gen_memory_block_mods()?.map(Into::into));
+        content.extend(part.gen_error_handler_mod().map(Into::into));
+        content.extend(part.gen_schedule_enum());
+        content.extend(part.gen_schedule_mod()?.map(Into::into));
+        content.extend(part.gen_schedule_impls().map(Into::into));
         content.extend(Context::gen_all(&part));
 
         let token_stream = input.to_token_stream();
@@ -162,3 +259,67 @@ impl Partition {
 //         }
 //     })
 // }
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, ItemMod};
+
+    use super::Partition;
+
+    fn from_mod(mut input: ItemMod) -> syn::Result<Partition> {
+        Partition::from_mod(parse_quote!(Hypervisor), &mut input)
+    }
+
+    #[test]
+    fn duplicate_apex_name() {
+        let err = from_mod(parse_quote! {
+            mod example {
+                #[start(cold)]
+                fn cold_start(ctx: start::Context) {}
+
+                #[start(warm)]
+                fn warm_start(ctx: start::Context) {}
+
+                #[sampling_out(name = "Shared", msg_size = "4B")]
+                struct Channel;
+
+                #[aperiodic(
+                    name = "Shared",
+                    time_capacity = "Infinite",
+                    stack_size = "8KB",
+                    base_priority = 1,
+                    deadline = "Soft"
+                )]
+                fn process(ctx: process::Context) {}
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate APEX name `Shared`");
+    }
+
+    #[test]
+    fn duplicate_module() {
+        let err = from_mod(parse_quote! {
+            mod example {
+                #[start(cold)]
+                fn cold_start(ctx: start::Context) {}
+
+                #[start(warm)]
+                fn warm_start(ctx: start::Context) {}
+
+                #[sampling_out(name = "Channel", msg_size = "4B")]
+                struct FooBar;
+
+                #[aperiodic(
+                    time_capacity = "Infinite",
+                    stack_size = "8KB",
+                    base_priority = 1,
+                    deadline = "Soft"
+                )]
+                fn foo_bar(ctx: foo_bar::Context) {}
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate module `foo_bar`");
+    }
+}
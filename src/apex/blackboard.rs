@@ -107,7 +107,9 @@ pub(crate) mod basic {
 
 /// abstraction for ARINC653P1-5 3.7.2.2 blackboard
 pub mod abstraction {
+    use core::borrow::Borrow;
     use core::marker::PhantomData;
+    use core::ops::Deref;
     use core::sync::atomic::AtomicPtr;
 
     use super::basic::ApexBlackboardP1;
@@ -133,11 +135,182 @@ pub mod abstraction {
         }
     }
 
+    /// Blackboard displaying messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedBlackboard<T: Pod, B: ApexBlackboardP1>(Blackboard<B>, PhantomData<T>);
+
+    impl<T: Pod, B: ApexBlackboardP1> Clone for TypedBlackboard<T, B> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, B: ApexBlackboardP1> Deref for TypedBlackboard<T, B> {
+        type Target = Blackboard<B>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, B: ApexBlackboardP1> TryFrom<Blackboard<B>> for TypedBlackboard<T, B> {
+        type Error = Error;
+
+        fn try_from(blackboard: Blackboard<B>) -> Result<Self, Self::Error> {
+            if blackboard.max_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+            Ok(TypedBlackboard(blackboard, PhantomData))
+        }
+    }
+
+    impl<T: Pod, B: ApexBlackboardP1> TypedBlackboard<T, B> {
+        /// # Errors
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        pub fn display(&self, msg: &T) -> Result<(), Error> {
+            self.0.display(msg.as_bytes())
+        }
+
+        /// [TypedBlackboard::display] with this blackboard as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedBlackboard::display]
+        pub fn display_ctx(&self, msg: &T) -> Result<(), ContextError> {
+            let id = self.id();
+            let msg = msg
+                .as_bytes()
+                .validate_write(T::SIZE)
+                .context(Operation::BlackboardDisplay, id)?;
+            B::display_blackboard(id, msg).context(Operation::BlackboardDisplay, id)
+        }
+
+        /// # Errors
+        /// - [Error::ReadError]: the displayed message is not of the size of `T`
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: there is no message on the blackboard
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn read(&self, timeout: SystemTime) -> Result<T, Error> {
+            let mut msg = T::zeroed();
+            let data = self.0.read(timeout, msg.as_bytes_mut())?;
+            if data.len() != core::mem::size_of::<T>() {
+                return Err(Error::ReadError);
+            }
+            Ok(msg)
+        }
+
+        /// [TypedBlackboard::read] with this blackboard as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedBlackboard::read]
+        pub fn read_ctx(&self, timeout: SystemTime) -> Result<T, ContextError> {
+            let id = self.id();
+            let mut msg = T::zeroed();
+            // The message is exactly as large as the messages of this blackboard
+            let len = unsafe { B::read_blackboard(id, timeout.into(), msg.as_bytes_mut()) }
+                .context(Operation::BlackboardRead, id)?;
+            if len != T::SIZE {
+                return Err(ContextError::new(
+                    Operation::BlackboardRead,
+                    id,
+                    Error::ReadError,
+                ));
+            }
+            Ok(msg)
+        }
+    }
+
+    /// Blackboard displaying messages of at most `MSG_SIZE` bytes
+    #[derive(Debug)]
+    pub struct ConstBlackboard<const MSG_SIZE: MessageSize, B: ApexBlackboardP1>(Blackboard<B>);
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> Clone for ConstBlackboard<MSG_SIZE, B> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> Deref for ConstBlackboard<MSG_SIZE, B> {
+        type Target = Blackboard<B>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> AsRef<Blackboard<B>>
+        for ConstBlackboard<MSG_SIZE, B>
+    {
+        fn as_ref(&self) -> &Blackboard<B> {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> Borrow<Blackboard<B>>
+        for ConstBlackboard<MSG_SIZE, B>
+    {
+        fn borrow(&self) -> &Blackboard<B> {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> TryFrom<Blackboard<B>>
+        for ConstBlackboard<MSG_SIZE, B>
+    {
+        type Error = Error;
+
+        fn try_from(blackboard: Blackboard<B>) -> Result<Self, Self::Error> {
+            if blackboard.max_size != MSG_SIZE {
+                return Err(Error::InvalidConfig);
+            }
+            Ok(ConstBlackboard(blackboard))
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, B: ApexBlackboardP1> ConstBlackboard<MSG_SIZE, B> {
+        /// Blackboard read into an owned [Message] of `MSG_SIZE` bytes
+        ///
+        /// `N` must be `MSG_SIZE`, anything else fails to build.
+        /// It can be inferred from the type of the returned [Message],
+        /// but not from `MSG_SIZE`, as that is a [MessageSize].
+        ///
+        /// ```
+        /// # use a653rs::prelude::*;
+        /// fn read<B: ApexBlackboardP1Ext>(
+        ///     blackboard: &ConstBlackboard<64, B>,
+        /// ) -> Result<Message<64>, Error> {
+        ///     blackboard.read_array(SystemTime::Infinite)
+        /// }
+        /// ```
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: there is no message on the blackboard
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn read_array<const N: usize>(&self, timeout: SystemTime) -> Result<Message<N>, Error> {
+            const { assert!(N == MSG_SIZE as usize, "N must be MSG_SIZE") }
+            let mut data = [0; N];
+            // The array is exactly as long as the `max_message_size` of this blackboard
+            let len = unsafe { self.0.read_unchecked(timeout, &mut data) }?.len();
+            Ok(Message::new(data, len))
+        }
+    }
+
     /// Free extra functions for implementer of [ApexBlackboardP1]
     pub trait ApexBlackboardP1Ext: ApexBlackboardP1 + Sized {
         /// # Errors
         /// - [Error::InvalidConfig]: blackboard with `name` does not exist
         fn get_blackboard(name: Name) -> Result<Blackboard<Self>, Error>;
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: blackboard with `name` does not exist
+        /// - [Error::InvalidConfig]: blackboard with `name` has a different `MSG_SIZE`
+        fn get_const_blackboard<const MSG_SIZE: MessageSize>(
+            name: Name,
+        ) -> Result<ConstBlackboard<MSG_SIZE, Self>, Error>;
     }
 
     impl<B: ApexBlackboardP1> ApexBlackboardP1Ext for B {
@@ -146,8 +319,9 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.7.2.2.6 this can only fail if the blackboard_id
             //  does not exist in the current partition.
             // But since we retrieve the blackboard_id directly from the hypervisor
-            //  there is no possible way for it not existing
-            let status = B::get_blackboard_status(id).unwrap();
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
+            let status = B::get_blackboard_status(id)?;
 
             Ok(Blackboard {
                 _b: Default::default(),
@@ -155,6 +329,12 @@ pub mod abstraction {
                 max_size: status.max_message_size,
             })
         }
+
+        fn get_const_blackboard<const MSG_SIZE: MessageSize>(
+            name: Name,
+        ) -> Result<ConstBlackboard<MSG_SIZE, B>, Error> {
+            B::get_blackboard(name)?.try_into()
+        }
     }
 
     impl<B: ApexBlackboardP1> Blackboard<B> {
@@ -222,24 +402,39 @@ pub mod abstraction {
             Ok(&buffer[..len])
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this blackboard does not exist anymore
+        pub fn try_clear(&self) -> Result<(), Error> {
+            B::clear_blackboard(self.id)?;
+            Ok(())
+        }
+
         /// # Panics
         /// if this blackboard does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn clear(&self) {
             // According to ARINC653P1-5 3.7.2.2.4 this can only fail if the blackboard_id
             //  does not exist in the current partition.
             // But since we retrieve the blackboard_id directly from the hypervisor
             //  there is no possible way for it not existing
-            B::clear_blackboard(self.id).unwrap()
+            self.try_clear().unwrap()
+        }
+
+        /// # Errors
+        /// - [Error::InvalidParam]: this blackboard does not exist anymore
+        pub fn try_status(&self) -> Result<BlackboardStatus, Error> {
+            Ok(B::get_blackboard_status(self.id)?)
         }
 
         /// # Panics
         /// if this blackboard does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> BlackboardStatus {
             // According to ARINC653P1-5 3.7.2.2.6 this can only fail if the blackboard_id
             //  does not exist in the current partition.
             // But since we retrieve the blackboard_id directly from the hypervisor
             //  there is no possible way for it not existing
-            B::get_blackboard_status(self.id).unwrap()
+            self.try_status().unwrap()
         }
     }
 
@@ -261,5 +456,31 @@ pub mod abstraction {
                 max_size: size,
             })
         }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BLACKBOARDS](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BLACKBOARDS) was reached
+        /// - [Error::NoAction]: a blackboard with given `name` already exists
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        pub fn create_typed_blackboard<T: Pod>(
+            &mut self,
+            name: Name,
+        ) -> Result<TypedBlackboard<T, B>, Error> {
+            let blackboard = self.create_blackboard(name, T::SIZE)?;
+            Ok(TypedBlackboard(blackboard, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BLACKBOARDS](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BLACKBOARDS) was reached
+        /// - [Error::NoAction]: a blackboard with given `name` already exists
+        /// - [Error::InvalidParam]: `MSG_SIZE` is zero
+        pub fn create_const_blackboard<const MSG_SIZE: MessageSize>(
+            &mut self,
+            name: Name,
+        ) -> Result<ConstBlackboard<MSG_SIZE, B>, Error> {
+            let blackboard = self.create_blackboard(name, MSG_SIZE)?;
+            Ok(ConstBlackboard(blackboard))
+        }
     }
 }
@@ -92,7 +92,9 @@ pub mod basic {
 
 /// abstraction for ARINC653P1-5 3.7.2.1 blackboard
 pub mod abstraction {
+    use core::borrow::Borrow;
     use core::marker::PhantomData;
+    use core::ops::Deref;
     use core::sync::atomic::AtomicPtr;
 
     use super::basic::ApexBufferP1;
@@ -120,11 +122,198 @@ pub mod abstraction {
         }
     }
 
+    /// Buffer transporting messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedBuffer<T: Pod, B: ApexBufferP1>(Buffer<B>, PhantomData<T>);
+
+    impl<T: Pod, B: ApexBufferP1> Clone for TypedBuffer<T, B> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, B: ApexBufferP1> Deref for TypedBuffer<T, B> {
+        type Target = Buffer<B>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, B: ApexBufferP1> TryFrom<Buffer<B>> for TypedBuffer<T, B> {
+        type Error = Error;
+
+        fn try_from(buffer: Buffer<B>) -> Result<Self, Self::Error> {
+            if buffer.max_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+            Ok(TypedBuffer(buffer, PhantomData))
+        }
+    }
+
+    impl<T: Pod, B: ApexBufferP1> TypedBuffer<T, B> {
+        /// # Errors
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND the buffer is full
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn send(&self, msg: &T, timeout: SystemTime) -> Result<(), Error> {
+            self.0.send(msg.as_bytes(), timeout)
+        }
+
+        /// [TypedBuffer::send] with this buffer as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedBuffer::send]
+        pub fn send_ctx(&self, msg: &T, timeout: SystemTime) -> Result<(), ContextError> {
+            let id = self.id();
+            let msg = msg
+                .as_bytes()
+                .validate_write(T::SIZE)
+                .context(Operation::BufferSend, id)?;
+            B::send_buffer(id, msg, timeout.into()).context(Operation::BufferSend, id)
+        }
+
+        /// # Errors
+        /// - [Error::ReadError]: the received message is not of the size of `T`
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: there is no message in the buffer
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn receive(&self, timeout: SystemTime) -> Result<T, Error> {
+            let mut msg = T::zeroed();
+            let data = self.0.receive(msg.as_bytes_mut(), timeout)?;
+            if data.len() != core::mem::size_of::<T>() {
+                return Err(Error::ReadError);
+            }
+            Ok(msg)
+        }
+
+        /// [TypedBuffer::receive] with this buffer as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedBuffer::receive]
+        pub fn receive_ctx(&self, timeout: SystemTime) -> Result<T, ContextError> {
+            let id = self.id();
+            let mut msg = T::zeroed();
+            // The message is exactly as large as the messages of this buffer
+            let len = unsafe { B::receive_buffer(id, timeout.into(), msg.as_bytes_mut()) }
+                .context(Operation::BufferReceive, id)?;
+            if len != T::SIZE {
+                return Err(ContextError::new(
+                    Operation::BufferReceive,
+                    id,
+                    Error::ReadError,
+                ));
+            }
+            Ok(msg)
+        }
+    }
+
+    /// Buffer transporting up to `NB_MSGS` messages of at most `MSG_SIZE` bytes
+    #[derive(Debug)]
+    pub struct ConstBuffer<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1>(
+        Buffer<B>,
+    );
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1> Clone
+        for ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1> Deref
+        for ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        type Target = Buffer<B>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1> AsRef<Buffer<B>>
+        for ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        fn as_ref(&self) -> &Buffer<B> {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1>
+        Borrow<Buffer<B>> for ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        fn borrow(&self) -> &Buffer<B> {
+            &self.0
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1>
+        TryFrom<Buffer<B>> for ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        type Error = Error;
+
+        fn try_from(buffer: Buffer<B>) -> Result<Self, Self::Error> {
+            if buffer.max_size != MSG_SIZE || buffer.max_number_msgs != NB_MSGS {
+                return Err(Error::InvalidConfig);
+            }
+            Ok(ConstBuffer(buffer))
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, B: ApexBufferP1>
+        ConstBuffer<MSG_SIZE, NB_MSGS, B>
+    {
+        /// Buffer receive into an owned [Message] of `MSG_SIZE` bytes
+        ///
+        /// `N` must be `MSG_SIZE`, anything else fails to build.
+        /// It can be inferred from the type of the returned [Message],
+        /// but not from `MSG_SIZE`, as that is a [MessageSize].
+        ///
+        /// ```
+        /// # use a653rs::prelude::*;
+        /// fn receive<B: ApexBufferP1Ext>(
+        ///     buffer: &ConstBuffer<64, 8, B>,
+        /// ) -> Result<Message<64>, Error> {
+        ///     buffer.receive_array(SystemTime::Infinite)
+        /// }
+        /// ```
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: there is no message in the buffer
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn receive_array<const N: usize>(
+            &self,
+            timeout: SystemTime,
+        ) -> Result<Message<N>, Error> {
+            const { assert!(N == MSG_SIZE as usize, "N must be MSG_SIZE") }
+            let mut data = [0; N];
+            // The array is exactly as long as the `max_message_size` of this buffer
+            let len = unsafe { self.0.receive_unchecked(timeout, &mut data) }?.len();
+            Ok(Message::new(data, len))
+        }
+    }
+
     /// Free extra functions for implementer of [ApexBufferP1]
     pub trait ApexBufferP1Ext: ApexBufferP1 + Sized {
         /// # Errors
         /// - [Error::InvalidConfig]: buffer with `name` does not exist
         fn get_buffer(name: Name) -> Result<Buffer<Self>, Error>;
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: buffer with `name` does not exist
+        /// - [Error::InvalidConfig]: buffer with `name` has a different `MSG_SIZE` or `NB_MSGS`
+        fn get_const_buffer<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange>(
+            name: Name,
+        ) -> Result<ConstBuffer<MSG_SIZE, NB_MSGS, Self>, Error>;
     }
 
     impl<B: ApexBufferP1> ApexBufferP1Ext for B {
@@ -133,8 +322,9 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.7.2.1.5 this can only fail if the buffer_id
             //  does not exist in the current partition.
             // But since we retrieve the buffer_id directly from the hypervisor
-            //  there is no possible way for it not existing
-            let status = B::get_buffer_status(id).unwrap();
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
+            let status = B::get_buffer_status(id)?;
 
             Ok(Buffer {
                 _b: Default::default(),
@@ -143,6 +333,12 @@ pub mod abstraction {
                 max_number_msgs: status.max_nb_message,
             })
         }
+
+        fn get_const_buffer<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange>(
+            name: Name,
+        ) -> Result<ConstBuffer<MSG_SIZE, NB_MSGS, B>, Error> {
+            B::get_buffer(name)?.try_into()
+        }
     }
 
     impl<B: ApexBufferP1> Buffer<B> {
@@ -171,7 +367,7 @@ pub mod abstraction {
         /// # Errors
         /// - [Error::WriteError]: the `buffer` is longer than the `max_message_size` specified for this buffer
         /// - [Error::WriteError]: `buffer` length is zero
-        pub fn send(&self, buffer: &mut [ApexByte], timeout: SystemTime) -> Result<(), Error> {
+        pub fn send(&self, buffer: &[ApexByte], timeout: SystemTime) -> Result<(), Error> {
             buffer.validate_write(self.max_size)?;
             B::send_buffer(self.id, buffer, timeout.into())?;
             Ok(())
@@ -216,14 +412,21 @@ pub mod abstraction {
             Ok(&buffer[..len])
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this buffer does not exist anymore
+        pub fn try_status(&self) -> Result<BufferStatus, Error> {
+            Ok(B::get_buffer_status(self.id)?)
+        }
+
         /// # Panics
         /// if this buffer does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> BufferStatus {
             // According to ARINC653P1-5 3.7.2.1.5 this can only fail if the buffer_id
             //  does not exist in the current partition.
             // But since we retrieve the buffer_id directly from the hypervisor
             //  there is no possible way for it not existing
-            B::get_buffer_status(self.id).unwrap()
+            self.try_status().unwrap()
         }
     }
 
@@ -249,5 +452,81 @@ pub mod abstraction {
                 max_number_msgs: range,
             })
         }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BUFFERS](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BUFFERS) was reached
+        /// - [Error::NoAction]: a buffer with given `name` already exists
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        /// - [Error::InvalidParam]: `range` is too large
+        pub fn create_typed_buffer<T: Pod>(
+            &mut self,
+            name: Name,
+            range: MessageRange,
+            qd: QueuingDiscipline,
+        ) -> Result<TypedBuffer<T, B>, Error> {
+            let buffer = self.create_buffer(name, T::SIZE, range, qd)?;
+            Ok(TypedBuffer(buffer, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BUFFERS](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_BUFFERS) was reached
+        /// - [Error::NoAction]: a buffer with given `name` already exists
+        /// - [Error::InvalidParam]: `MSG_SIZE` is zero
+        /// - [Error::InvalidParam]: `NB_MSGS` is too large
+        pub fn create_const_buffer<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange>(
+            &mut self,
+            name: Name,
+            qd: QueuingDiscipline,
+        ) -> Result<ConstBuffer<MSG_SIZE, NB_MSGS, B>, Error> {
+            let buffer = self.create_buffer(name, MSG_SIZE, NB_MSGS, qd)?;
+            Ok(ConstBuffer(buffer))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use crate::apex::mock::MockHypervisor;
+    use crate::prelude::*;
+
+    const INSTANT: SystemTime = SystemTime::Normal(Duration::ZERO);
+
+    #[test]
+    fn ctx_keeps_return_code() {
+        // This is safe because the test is the only user of the context
+        let mut ctx = unsafe { StartContext::<MockHypervisor>::new() };
+        let buffer = ctx
+            .create_typed_buffer::<u32>(
+                Name::from_str("buffer").unwrap(),
+                1,
+                QueuingDiscipline::Fifo,
+            )
+            .unwrap();
+
+        let empty = buffer.receive_ctx(INSTANT).unwrap_err();
+        assert_eq!(empty.operation, Operation::BufferReceive);
+        assert_eq!(empty.object, Object::Id(buffer.id()));
+        assert_eq!(empty.error, Error::NotAvailable);
+        assert_eq!(empty.return_code, Some(ErrorReturnCode::NotAvailable));
+
+        buffer.send_ctx(&42, INSTANT).unwrap();
+        let full = buffer.send_ctx(&43, INSTANT).unwrap_err();
+        assert_eq!(full.operation, Operation::BufferSend);
+        assert_eq!(full.return_code, Some(ErrorReturnCode::NotAvailable));
+        assert_eq!(buffer.receive_ctx(INSTANT), Ok(42));
+    }
+
+    #[test]
+    fn ctx_of_plain_error_has_no_return_code() {
+        let err = Err::<(), _>(Error::ReadError)
+            .context(Operation::BufferReceive, Object::Unspecified)
+            .unwrap_err();
+        assert_eq!(err.error, Error::ReadError);
+        assert_eq!(err.return_code, None);
     }
 }
@@ -32,6 +32,7 @@ pub mod basic {
     }
 
     /// ARINC653P1-5 3.8.1
+    #[allow(unpredictable_function_pointer_comparisons)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct ErrorStatus {
         /// related implementation dependent address
@@ -116,6 +117,64 @@ pub mod abstraction {
     };
     use crate::prelude::*;
 
+    /// Fixed-size formatting buffer for messages reported to the health monitor
+    ///
+    /// Formatted text beyond [MAX_ERROR_MESSAGE_SIZE] is silently truncated at a char boundary.
+    #[derive(Debug)]
+    pub(crate) struct MessageWriter {
+        buffer: [ApexByte; MAX_ERROR_MESSAGE_SIZE],
+        len: usize,
+    }
+
+    impl MessageWriter {
+        pub(crate) fn new() -> Self {
+            Self {
+                buffer: [0; MAX_ERROR_MESSAGE_SIZE],
+                len: 0,
+            }
+        }
+
+        pub(crate) fn as_bytes(&self) -> &[ApexByte] {
+            &self.buffer[..self.len]
+        }
+    }
+
+    /// Formats `args` into a [MessageWriter] and passes the possibly truncated message to `f`
+    pub(crate) fn with_message<R>(
+        args: core::fmt::Arguments,
+        f: impl FnOnce(&[ApexByte]) -> R,
+    ) -> R {
+        let mut msg = MessageWriter::new();
+        // The writer never fails, it truncates instead
+        let _ = core::fmt::Write::write_fmt(&mut msg, args);
+        f(msg.as_bytes())
+    }
+
+    impl core::fmt::Write for MessageWriter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let mut n = s.len().min(MAX_ERROR_MESSAGE_SIZE - self.len);
+            while !s.is_char_boundary(n) {
+                n -= 1;
+            }
+            self.buffer[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+            self.len += n;
+            Ok(())
+        }
+    }
+
+    impl ContextError {
+        /// Report this error to the health monitor, truncated to [MAX_ERROR_MESSAGE_SIZE]
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: the message was rejected by the hypervisor
+        pub fn report<E: ApexErrorP4Ext>(&self) -> Result<(), Error> {
+            with_message(
+                format_args!("{self}"),
+                <E as ApexErrorP4Ext>::report_application_message,
+            )
+        }
+    }
+
     /// Free extra functions for implementer of [ApexErrorP4]
     pub trait ApexErrorP4Ext: ApexErrorP4 {
         /// report message to health monitor
@@ -188,3 +247,51 @@ pub mod abstraction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::fmt::Write;
+
+    use super::abstraction::MessageWriter;
+    use super::basic::MAX_ERROR_MESSAGE_SIZE;
+
+    #[test]
+    fn message_fits_exactly() {
+        let mut msg = MessageWriter::new();
+        write!(msg, "{}", "a".repeat(MAX_ERROR_MESSAGE_SIZE)).unwrap();
+        assert_eq!(msg.as_bytes().len(), MAX_ERROR_MESSAGE_SIZE);
+
+        // A full writer silently drops everything else
+        write!(msg, "b").unwrap();
+        assert_eq!(
+            msg.as_bytes(),
+            "a".repeat(MAX_ERROR_MESSAGE_SIZE).as_bytes()
+        );
+    }
+
+    #[test]
+    fn message_truncates_at_char_boundary() {
+        let mut msg = MessageWriter::new();
+        let prefix = "a".repeat(MAX_ERROR_MESSAGE_SIZE - 1);
+        // 'é' takes two bytes, only one of which would fit
+        write!(msg, "{prefix}é").unwrap();
+        assert_eq!(msg.as_bytes(), prefix.as_bytes());
+
+        let mut msg = MessageWriter::new();
+        let prefix = "a".repeat(MAX_ERROR_MESSAGE_SIZE - 2);
+        write!(msg, "{prefix}é").unwrap();
+        assert_eq!(msg.as_bytes(), std::format!("{prefix}é").as_bytes());
+    }
+
+    #[test]
+    fn message_is_written_in_pieces() {
+        let mut msg = MessageWriter::new();
+        let prefix = "a".repeat(MAX_ERROR_MESSAGE_SIZE - 3);
+        write!(msg, "{prefix}").unwrap();
+        write!(msg, "€").unwrap();
+        write!(msg, "b€").unwrap();
+        assert_eq!(msg.as_bytes(), std::format!("{prefix}€").as_bytes());
+    }
+}
@@ -136,28 +136,64 @@ pub mod abstraction {
             self.id
         }
 
+        /// Change to [EventState::Up]
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: this event does not exist anymore
+        pub fn try_set(&self) -> Result<(), Error> {
+            E::set_event(self.id)?;
+            Ok(())
+        }
+
+        /// [Event::try_set] with this event as context of the error
+        ///
+        /// # Errors
+        /// - errors of [Event::try_set]
+        pub fn set_ctx(&self) -> Result<(), ContextError> {
+            E::set_event(self.id).context(Operation::EventSet, self.id)
+        }
+
         /// Change to [EventState::Up]
         ///
         /// # Panics
         /// if this event does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn set(&self) {
             // According to ARINC653P1-5 3.7.2.4.2 this can only fail if the event_id
             //  does not exist in the current partition.
             // But since we retrieve the event_id directly from the hypervisor
             //  there is no possible way for it not existing
-            E::set_event(self.id).unwrap();
+            self.try_set().unwrap();
+        }
+
+        /// Change to [EventState::Down]
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: this event does not exist anymore
+        pub fn try_reset(&self) -> Result<(), Error> {
+            E::reset_event(self.id)?;
+            Ok(())
+        }
+
+        /// [Event::try_reset] with this event as context of the error
+        ///
+        /// # Errors
+        /// - errors of [Event::try_reset]
+        pub fn reset_ctx(&self) -> Result<(), ContextError> {
+            E::reset_event(self.id).context(Operation::EventReset, self.id)
         }
 
         /// Change to [EventState::Down]
         ///
         /// # Panics
         /// if this event does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn reset(&self) {
             // According to ARINC653P1-5 3.7.2.4.3 this can only fail if the event_id
             //  does not exist in the current partition.
             // But since we retrieve the event_id directly from the hypervisor
             //  there is no possible way for it not existing
-            E::reset_event(self.id).unwrap();
+            self.try_reset().unwrap();
         }
 
         /// wait for this event to occur
@@ -173,16 +209,33 @@ pub mod abstraction {
             Ok(())
         }
 
+        /// [Event::wait] with this event as context of the error
+        ///
+        /// # Errors
+        /// - errors of [Event::wait]
+        pub fn wait_ctx(&self, timeout: SystemTime) -> Result<(), ContextError> {
+            E::wait_event(self.id, timeout.into()).context(Operation::EventWait, self.id)
+        }
+
+        /// get current event status
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: this event does not exist anymore
+        pub fn try_status(&self) -> Result<EventStatus, Error> {
+            Ok(E::get_event_status(self.id)?)
+        }
+
         /// get current event status
         ///
         /// # Panics
         /// if this event does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> EventStatus {
             // According to ARINC653P1-5 3.7.2.4.6 this can only fail if the event_id
             //  does not exist in the current partition.
             // But since we retrieve the event_id directly from the hypervisor
             //  there is no possible way for it not existing
-            E::get_event_status(self.id).unwrap()
+            self.try_status().unwrap()
         }
     }
 
@@ -0,0 +1,354 @@
+/// abstraction routing records of the `log` crate to ARINC653 services
+pub mod abstraction {
+    use core::marker::PhantomData;
+    #[cfg(target_has_atomic = "64")]
+    use core::sync::atomic::AtomicU64;
+    use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
+    use core::time::Duration;
+
+    pub use log::LevelFilter;
+    use log::{Log, Metadata, Record};
+
+    use crate::apex::error::abstraction::with_message;
+    use crate::apex::error::basic::ApexErrorP4;
+    use crate::prelude::*;
+
+    /// Destination of the messages formatted by an [ApexLogger]
+    pub trait LogSink: Send + Sync {
+        /// Write a single message of at most [MAX_ERROR_MESSAGE_SIZE] bytes
+        ///
+        /// Failures are ignored, as there is nowhere to report them to.
+        fn write(&self, message: &[ApexByte]);
+    }
+
+    /// [LogSink] reporting messages to the health monitor via
+    /// [report_application_message](ApexErrorP4Ext::report_application_message)
+    #[derive(Debug)]
+    pub struct HealthMonitorSink<E: ApexErrorP4>(PhantomData<AtomicPtr<E>>);
+
+    impl<E: ApexErrorP4> HealthMonitorSink<E> {
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<E: ApexErrorP4> Default for HealthMonitorSink<E> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<E: ApexErrorP4> LogSink for HealthMonitorSink<E> {
+        fn write(&self, message: &[ApexByte]) {
+            let _ = <E as ApexErrorP4Ext>::report_application_message(message);
+        }
+    }
+
+    /// [LogSink] sending messages to a central logging partition
+    ///
+    /// Messages are sent without waiting and truncated to the message size of the port.
+    /// Hence they are lost if the queue is full.
+    impl<Q: ApexQueuingPortP4Ext> LogSink for QueuingPortSender<Q> {
+        fn write(&self, message: &[ApexByte]) {
+            let len = message.len().min(self.size());
+            let _ = self.send(&message[..len], SystemTime::Normal(Duration::ZERO));
+        }
+    }
+
+    /// Maximum number of messages a [RateLimitedLogger] forwards per `interval`
+    ///
+    /// With `max_messages` of zero, all records are dropped.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RateLimit {
+        pub max_messages: u32,
+        pub interval: Duration,
+    }
+
+    /// Logger for the `log` crate, forwarding records to a [LogSink]
+    ///
+    /// Each record is formatted as `LEVEL target: message` into a buffer on the stack
+    /// and truncated to [MAX_ERROR_MESSAGE_SIZE] bytes.
+    /// A [RateLimit] is added with [ApexLogger::with_rate_limit].
+    ///
+    /// As [log::set_logger] requires a `'static` logger, it is usually placed in a `static`
+    /// or in a [StartCell] if the sink is created during the partition start.
+    #[derive(Debug)]
+    pub struct ApexLogger<S: LogSink> {
+        sink: S,
+        level: LevelFilter,
+    }
+
+    impl<S: LogSink> ApexLogger<S> {
+        pub const fn new(sink: S, level: LevelFilter) -> Self {
+            Self { sink, level }
+        }
+
+        /// Limits the forwarded records to `rate_limit`, measured with the time of `T`
+        #[cfg(target_has_atomic = "64")]
+        pub const fn with_rate_limit<T: ApexTimeP4Ext>(
+            self,
+            rate_limit: RateLimit,
+        ) -> RateLimitedLogger<T, S> {
+            RateLimitedLogger {
+                _t: PhantomData,
+                logger: self,
+                rate_limit,
+                window_start: AtomicU64::new(0),
+                count: AtomicU32::new(0),
+                dropped: AtomicU32::new(0),
+            }
+        }
+
+        pub fn level(&self) -> LevelFilter {
+            self.level
+        }
+
+        pub fn sink(&self) -> &S {
+            &self.sink
+        }
+
+        /// Use this logger for all records of the `log` crate
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: a logger was already installed
+        pub fn install(&'static self) -> Result<(), Error> {
+            install(self, self.level)
+        }
+
+        fn emit(&self, args: core::fmt::Arguments) {
+            with_message(args, |msg| self.sink.write(msg));
+        }
+    }
+
+    impl<S: LogSink> Log for ApexLogger<S> {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= self.level
+        }
+
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            self.emit(format_args!(
+                "{} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            ));
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// [ApexLogger] dropping the records which exceed a [RateLimit]
+    ///
+    /// The number of dropped records is logged once the next interval starts.
+    /// Records are not limited while the current time of `T` is not available.
+    ///
+    /// The start of the current interval is kept in nanoseconds in an [AtomicU64],
+    /// so this is only available on targets with 64-bit atomics.
+    #[cfg(target_has_atomic = "64")]
+    #[derive(Debug)]
+    pub struct RateLimitedLogger<T: ApexTimeP4Ext, S: LogSink> {
+        _t: PhantomData<AtomicPtr<T>>,
+        logger: ApexLogger<S>,
+        rate_limit: RateLimit,
+        window_start: AtomicU64,
+        count: AtomicU32,
+        dropped: AtomicU32,
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    impl<T: ApexTimeP4Ext, S: LogSink> RateLimitedLogger<T, S> {
+        pub fn logger(&self) -> &ApexLogger<S> {
+            &self.logger
+        }
+
+        pub fn rate_limit(&self) -> RateLimit {
+            self.rate_limit
+        }
+
+        /// Use this logger for all records of the `log` crate
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: a logger was already installed
+        pub fn install(&'static self) -> Result<(), Error> {
+            install(self, self.logger.level)
+        }
+
+        /// Whether the rate limit admits another message
+        fn admit(&self) -> bool {
+            let limit = self.rate_limit;
+            if limit.max_messages == 0 {
+                return false;
+            }
+            // Without the current time, messages can not be rate limited
+            let Ok(now) = T::now() else {
+                return true;
+            };
+            // Nanoseconds only overflow after 584 years, longer intervals never elapse
+            let now = u64::try_from(now.as_duration().as_nanos()).unwrap_or(u64::MAX);
+            let interval = u64::try_from(limit.interval.as_nanos()).unwrap_or(u64::MAX);
+            let start = self.window_start.load(Ordering::Relaxed);
+            // Only the process winning the exchange starts the new window and reports the drops
+            if now.saturating_sub(start) >= interval
+                && self
+                    .window_start
+                    .compare_exchange(start, now, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok()
+            {
+                self.count.store(0, Ordering::Relaxed);
+                let dropped = self.dropped.swap(0, Ordering::Relaxed);
+                if dropped > 0 {
+                    self.logger
+                        .emit(format_args!("{dropped} log messages suppressed"));
+                }
+            }
+            if self.count.fetch_add(1, Ordering::Relaxed) < limit.max_messages {
+                return true;
+            }
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    impl<T: ApexTimeP4Ext, S: LogSink> Log for RateLimitedLogger<T, S> {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            self.logger.enabled(metadata)
+        }
+
+        fn log(&self, record: &Record) {
+            if self.enabled(record.metadata()) && self.admit() {
+                self.logger.log(record);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn install(logger: &'static dyn Log, level: LevelFilter) -> Result<(), Error> {
+        log::set_logger(logger).map_err(|_| Error::NoAction)?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::time::Duration;
+
+    use log::{Level, Log, Record};
+
+    use super::abstraction::*;
+    use crate::apex::mock::MockHypervisor;
+    use crate::prelude::MAX_ERROR_MESSAGE_SIZE;
+
+    type Sink = HealthMonitorSink<MockHypervisor>;
+
+    fn logger() -> ApexLogger<Sink> {
+        MockHypervisor::set_time(0);
+        MockHypervisor::take_messages();
+        ApexLogger::new(HealthMonitorSink::new(), LevelFilter::Info)
+    }
+
+    fn rate_limited(max_messages: u32) -> RateLimitedLogger<MockHypervisor, Sink> {
+        logger().with_rate_limit(RateLimit {
+            max_messages,
+            interval: Duration::from_millis(100),
+        })
+    }
+
+    fn log(logger: &impl Log, level: Level, message: &str) {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .target("test")
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn records_are_formatted_and_filtered() {
+        let logger = logger();
+        log(&logger, Level::Warn, "shown");
+        log(&logger, Level::Debug, "hidden");
+        assert_eq!(MockHypervisor::take_messages(), ["WARN test: shown"]);
+    }
+
+    #[test]
+    fn records_are_truncated() {
+        let logger = logger();
+        log(
+            &logger,
+            Level::Info,
+            &"a".repeat(2 * MAX_ERROR_MESSAGE_SIZE),
+        );
+        let messages = MockHypervisor::take_messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].len(), MAX_ERROR_MESSAGE_SIZE);
+        assert!(messages[0].starts_with("INFO test: aaa"));
+    }
+
+    #[test]
+    fn rate_limit_drops_and_reports() {
+        let logger = rate_limited(2);
+        for i in 0..5 {
+            log(&logger, Level::Info, &std::format!("{i}"));
+        }
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["INFO test: 0", "INFO test: 1"]
+        );
+
+        // The drops are reported once by the first record of the next window
+        MockHypervisor::advance(Duration::from_millis(100));
+        log(&logger, Level::Info, "5");
+        log(&logger, Level::Info, "6");
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["3 log messages suppressed", "INFO test: 5", "INFO test: 6"]
+        );
+
+        // Without drops, a new window reports nothing
+        MockHypervisor::advance(Duration::from_millis(150));
+        log(&logger, Level::Info, "7");
+        assert_eq!(MockHypervisor::take_messages(), ["INFO test: 7"]);
+    }
+
+    #[test]
+    fn long_interval_does_not_restart_early() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let logger = logger().with_rate_limit::<MockHypervisor>(RateLimit {
+            max_messages: 1,
+            interval: 60 * day,
+        });
+        log(&logger, Level::Info, "0");
+        log(&logger, Level::Info, "1");
+        assert_eq!(MockHypervisor::take_messages(), ["INFO test: 0"]);
+
+        // Past 2^32 milliseconds, but still within the interval
+        MockHypervisor::advance(50 * day);
+        log(&logger, Level::Info, "2");
+        assert!(MockHypervisor::take_messages().is_empty());
+
+        MockHypervisor::advance(10 * day);
+        log(&logger, Level::Info, "3");
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["2 log messages suppressed", "INFO test: 3"]
+        );
+    }
+
+    #[test]
+    fn zero_rate_limit_drops_all() {
+        let logger = rate_limited(0);
+        log(&logger, Level::Error, "dropped");
+        MockHypervisor::advance(Duration::from_millis(100));
+        log(&logger, Level::Error, "dropped");
+        assert!(MockHypervisor::take_messages().is_empty());
+    }
+}
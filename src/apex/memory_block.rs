@@ -42,6 +42,8 @@ pub mod basic {
 /// abstraction for ARINC653P2-4 3.9 memory blocks
 pub mod abstraction {
     use core::marker::PhantomData;
+    use core::ops::Deref;
+    use core::ptr::NonNull;
     use core::slice::from_raw_parts_mut;
 
     use super::basic::{ApexMemoryBlockP2, ApexMemoryBlockStatus};
@@ -118,4 +120,111 @@ pub mod abstraction {
             self.mode
         }
     }
+
+    /// Read-only view on a `T` at the start of a memory block
+    ///
+    /// All accesses are volatile copies of the whole `T`.
+    /// Because the memory block can change externally, a read value may be torn
+    /// if it is written at the same time.
+    #[derive(Debug)]
+    pub struct TypedMemoryBlock<T: Pod, M: ApexMemoryBlockP2> {
+        _m: PhantomData<M>,
+        address: NonNull<T>,
+    }
+
+    // The memory block is shared memory, which is only ever accessed by volatile copies of `T`
+    unsafe impl<T: Pod, M: ApexMemoryBlockP2> Send for TypedMemoryBlock<T, M> {}
+    unsafe impl<T: Pod, M: ApexMemoryBlockP2> Sync for TypedMemoryBlock<T, M> {}
+
+    impl<T: Pod, M: ApexMemoryBlockP2> Clone for TypedMemoryBlock<T, M> {
+        fn clone(&self) -> Self {
+            Self {
+                _m: self._m,
+                address: self.address,
+            }
+        }
+    }
+
+    impl<T: Pod, M: ApexMemoryBlockP2> TryFrom<MemoryBlock<M>> for TypedMemoryBlock<T, M> {
+        type Error = Error;
+
+        fn try_from(block: MemoryBlock<M>) -> Result<Self, Self::Error> {
+            if block.memory.len() < core::mem::size_of::<T>() {
+                return Err(Error::InvalidConfig);
+            }
+            let address = block.memory.as_mut_ptr().cast::<T>();
+            if !address.is_aligned() {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedMemoryBlock {
+                _m: Default::default(),
+                // Memory blocks always have a valid start address
+                address: NonNull::new(address).ok_or(Error::InvalidConfig)?,
+            })
+        }
+    }
+
+    impl<T: Pod, M: ApexMemoryBlockP2> TypedMemoryBlock<T, M> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: memory block with `name` does not exist
+        /// - [Error::InvalidConfig]: memory block with `name` is too small or not aligned for `T`
+        pub fn from_name(name: Name) -> Result<TypedMemoryBlock<T, M>, Error> {
+            M::get_memory_block(name)?.try_into()
+        }
+
+        pub fn read(&self) -> T {
+            // The address is valid and aligned for `T`, which allows any bit pattern
+            unsafe { self.address.as_ptr().read_volatile() }
+        }
+    }
+
+    /// Read-write view on a `T` at the start of a memory block
+    ///
+    /// All accesses are volatile copies of the whole `T`.
+    /// Because the memory block can change externally, a read value may be torn
+    /// if it is written at the same time.
+    #[derive(Debug)]
+    pub struct TypedMemoryBlockMut<T: Pod, M: ApexMemoryBlockP2>(TypedMemoryBlock<T, M>);
+
+    impl<T: Pod, M: ApexMemoryBlockP2> Clone for TypedMemoryBlockMut<T, M> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<T: Pod, M: ApexMemoryBlockP2> Deref for TypedMemoryBlockMut<T, M> {
+        type Target = TypedMemoryBlock<T, M>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, M: ApexMemoryBlockP2> TryFrom<MemoryBlock<M>> for TypedMemoryBlockMut<T, M> {
+        type Error = Error;
+
+        fn try_from(block: MemoryBlock<M>) -> Result<Self, Self::Error> {
+            if block.mode != MemoryBlockMode::ReadWrite {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedMemoryBlockMut(block.try_into()?))
+        }
+    }
+
+    impl<T: Pod, M: ApexMemoryBlockP2> TypedMemoryBlockMut<T, M> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: memory block with `name` does not exist
+        /// - [Error::InvalidConfig]: memory block with `name` is too small or not aligned for `T`
+        /// - [Error::InvalidConfig]: memory block with `name` is not writable
+        pub fn from_name(name: Name) -> Result<TypedMemoryBlockMut<T, M>, Error> {
+            M::get_memory_block(name)?.try_into()
+        }
+
+        pub fn write(&self, value: &T) {
+            // The address is valid, aligned and writable for `T`
+            unsafe { self.0.address.as_ptr().write_volatile(*value) }
+        }
+    }
 }
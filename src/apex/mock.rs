@@ -0,0 +1,391 @@
+extern crate std;
+
+use core::cell::RefCell;
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::string::String;
+use std::vec::Vec;
+
+use crate::apex::buffer::basic::*;
+use crate::apex::error::basic::{ApexErrorP4, ErrorCode};
+use crate::apex::event::basic::*;
+use crate::apex::mutex::basic::*;
+use crate::apex::process::basic::*;
+use crate::apex::time::basic::{ApexSystemTime, ApexTimeP1, ApexTimeP4, INFINITE_TIME_VALUE};
+use crate::apex::types::basic::*;
+
+/// Buffer of [MockHypervisor], which never blocks
+#[derive(Debug)]
+struct MockBuffer {
+    max_message_size: MessageSize,
+    max_nb_message: MessageRange,
+    messages: VecDeque<Vec<ApexByte>>,
+}
+
+/// Process state of the test calling [MockHypervisor]
+///
+/// Mutexes, events and buffers are identified by their index.
+#[derive(Debug)]
+struct State {
+    time: ApexSystemTime,
+    deadline: ApexSystemTime,
+    period: ApexSystemTime,
+    messages: Vec<String>,
+    /// Lock count of each mutex
+    mutexes: Vec<LockCount>,
+    events: Vec<EventState>,
+    buffers: Vec<MockBuffer>,
+}
+
+std::thread_local! {
+    static STATE: RefCell<State> = const {
+        RefCell::new(State {
+            time: 0,
+            deadline: INFINITE_TIME_VALUE,
+            period: INFINITE_TIME_VALUE,
+            messages: Vec::new(),
+            mutexes: Vec::new(),
+            events: Vec::new(),
+            buffers: Vec::new(),
+        })
+    };
+}
+
+/// Hypervisor for unit tests, whose time and process status are controlled by the test
+///
+/// The state is local to the thread running the test.
+/// Waiting never blocks: an instant timeout fails with [ErrorReturnCode::NotAvailable],
+/// any other timeout advances the time and fails with [ErrorReturnCode::TimedOut].
+#[derive(Debug)]
+pub(crate) struct MockHypervisor;
+
+impl MockHypervisor {
+    pub(crate) fn set_time(time: ApexSystemTime) {
+        STATE.with_borrow_mut(|s| s.time = time);
+    }
+
+    pub(crate) fn advance(by: Duration) {
+        STATE.with_borrow_mut(|s| s.time += by.as_nanos() as ApexSystemTime);
+    }
+
+    pub(crate) fn set_deadline(deadline: ApexSystemTime) {
+        STATE.with_borrow_mut(|s| s.deadline = deadline);
+    }
+
+    pub(crate) fn set_period(period: ApexSystemTime) {
+        STATE.with_borrow_mut(|s| s.period = period);
+    }
+
+    /// All messages reported to the health monitor since the last call
+    pub(crate) fn take_messages() -> Vec<String> {
+        STATE.with_borrow_mut(|s| core::mem::take(&mut s.messages))
+    }
+
+    extern "C" fn entry_point() {}
+
+    /// Result of waiting for `time_out` on a resource, which is not available
+    fn wait(time_out: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        match time_out {
+            0 => Err(ErrorReturnCode::NotAvailable),
+            INFINITE_TIME_VALUE => unimplemented!("waiting forever in a test"),
+            _ => {
+                STATE.with_borrow_mut(|s| s.time += time_out);
+                Err(ErrorReturnCode::TimedOut)
+            }
+        }
+    }
+
+    fn index(id: ApexLongInteger) -> usize {
+        usize::try_from(id).unwrap()
+    }
+}
+
+impl ApexTimeP4 for MockHypervisor {
+    fn periodic_wait() -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_time() -> ApexSystemTime {
+        STATE.with_borrow(|s| s.time)
+    }
+}
+
+impl ApexTimeP1 for MockHypervisor {
+    fn timed_wait(delay_time: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| s.time += delay_time);
+        Ok(())
+    }
+
+    fn replenish(_budget_time: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+}
+
+impl ApexErrorP4 for MockHypervisor {
+    fn report_application_message(message: &[ApexByte]) -> Result<(), ErrorReturnCode> {
+        let message = String::from_utf8(message.to_vec()).unwrap();
+        STATE.with_borrow_mut(|s| s.messages.push(message));
+        Ok(())
+    }
+
+    fn raise_application_error(
+        _error_code: ErrorCode,
+        _message: &[ApexByte],
+    ) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+}
+
+impl ApexProcessP4 for MockHypervisor {
+    fn create_process(_attributes: &ApexProcessAttribute) -> Result<ProcessId, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn start(_process_id: ProcessId) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+}
+
+impl ApexProcessP1 for MockHypervisor {
+    fn set_priority(_process_id: ProcessId, _priority: Priority) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn suspend_self(_time_out: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn suspend(_process_id: ProcessId) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn resume(_process_id: ProcessId) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn stop_self() {
+        unimplemented!()
+    }
+
+    fn stop(_process_id: ProcessId) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn delayed_start(
+        _process_id: ProcessId,
+        _delay_time: ApexSystemTime,
+    ) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn lock_preemption() -> Result<LockLevel, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn unlock_preemption() -> Result<LockLevel, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_my_id() -> Result<ProcessId, ErrorReturnCode> {
+        Ok(1)
+    }
+
+    fn get_process_id(_process_name: ProcessName) -> Result<ProcessId, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_process_status(_process_id: ProcessId) -> Result<ApexProcessStatus, ErrorReturnCode> {
+        let mut name = [0; MAX_NAME_LENGTH];
+        name[..4].copy_from_slice(b"mock");
+        Ok(STATE.with_borrow(|s| ApexProcessStatus {
+            deadline_time: s.deadline,
+            current_priority: 1,
+            process_state: ProcessState::Running,
+            attributes: ApexProcessAttribute {
+                period: s.period,
+                time_capacity: INFINITE_TIME_VALUE,
+                entry_point: Self::entry_point,
+                stack_size: 0,
+                base_priority: 1,
+                deadline: Deadline::Soft,
+                name,
+            },
+        }))
+    }
+
+    fn initialize_process_core_affinity(
+        _process_id: ProcessId,
+        _processor_core_id: ProcessorCoreId,
+    ) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_my_processor_core_id() -> ProcessorCoreId {
+        unimplemented!()
+    }
+
+    fn get_my_index() -> Result<ProcessIndex, ErrorReturnCode> {
+        unimplemented!()
+    }
+}
+
+impl ApexMutexP1 for MockHypervisor {
+    fn create_mutex(
+        _mutex_name: MutexName,
+        _mutex_priority: Priority,
+        _queuing_discipline: QueuingDiscipline,
+    ) -> Result<MutexId, ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| {
+            s.mutexes.push(0);
+            Ok(s.mutexes.len() as MutexId - 1)
+        })
+    }
+
+    fn acquire_mutex(mutex_id: MutexId, _time_out: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        // There is only one process, so the mutex is always available to it
+        STATE.with_borrow_mut(|s| s.mutexes[Self::index(mutex_id)] += 1);
+        Ok(())
+    }
+
+    fn release_mutex(mutex_id: MutexId) -> Result<(), ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| match &mut s.mutexes[Self::index(mutex_id)] {
+            0 => Err(ErrorReturnCode::InvalidMode),
+            count => {
+                *count -= 1;
+                Ok(())
+            }
+        })
+    }
+
+    fn reset_mutex(_mutex_id: MutexId, _process_id: ProcessId) -> Result<(), ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_mutex_id(_mutex_name: MutexName) -> Result<MutexId, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_mutex_status(mutex_id: MutexId) -> Result<MutexStatus, ErrorReturnCode> {
+        let lock_count = STATE.with_borrow(|s| s.mutexes[Self::index(mutex_id)]);
+        Ok(MutexStatus {
+            mutex_owner: if lock_count > 0 { 1 } else { NULL_PROCESS_ID },
+            mutex_state: if lock_count > 0 {
+                MutexState::Owned
+            } else {
+                MutexState::Available
+            },
+            mutex_priority: 1,
+            lock_count,
+            waiting_processes: 0,
+        })
+    }
+
+    fn get_process_mutex_state(_process_id: ProcessId) -> Result<MutexId, ErrorReturnCode> {
+        unimplemented!()
+    }
+}
+
+impl ApexEventP1 for MockHypervisor {
+    fn create_event(_event_name: EventName) -> Result<EventId, ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| {
+            s.events.push(EventState::Down);
+            Ok(s.events.len() as EventId - 1)
+        })
+    }
+
+    fn set_event(event_id: EventId) -> Result<(), ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| s.events[Self::index(event_id)] = EventState::Up);
+        Ok(())
+    }
+
+    fn reset_event(event_id: EventId) -> Result<(), ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| s.events[Self::index(event_id)] = EventState::Down);
+        Ok(())
+    }
+
+    fn wait_event(event_id: EventId, time_out: ApexSystemTime) -> Result<(), ErrorReturnCode> {
+        match STATE.with_borrow(|s| s.events[Self::index(event_id)]) {
+            EventState::Up => Ok(()),
+            EventState::Down => Self::wait(time_out),
+        }
+    }
+
+    fn get_event_id(_event_name: EventName) -> Result<EventId, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_event_status(event_id: EventId) -> Result<EventStatus, ErrorReturnCode> {
+        Ok(EventStatus {
+            event_state: STATE.with_borrow(|s| s.events[Self::index(event_id)]),
+            waiting_processes: 0,
+        })
+    }
+}
+
+impl ApexBufferP1 for MockHypervisor {
+    fn create_buffer(
+        _buffer_name: BufferName,
+        max_message_size: MessageSize,
+        max_nb_message: MessageRange,
+        _queuing_discipline: QueuingDiscipline,
+    ) -> Result<BufferId, ErrorReturnCode> {
+        STATE.with_borrow_mut(|s| {
+            s.buffers.push(MockBuffer {
+                max_message_size,
+                max_nb_message,
+                messages: VecDeque::new(),
+            });
+            Ok(s.buffers.len() as BufferId - 1)
+        })
+    }
+
+    fn send_buffer(
+        buffer_id: BufferId,
+        message: &[ApexByte],
+        time_out: ApexSystemTime,
+    ) -> Result<(), ErrorReturnCode> {
+        let sent = STATE.with_borrow_mut(|s| {
+            let buffer = &mut s.buffers[Self::index(buffer_id)];
+            assert!(message.len() <= buffer.max_message_size as usize);
+            if buffer.messages.len() >= buffer.max_nb_message as usize {
+                return false;
+            }
+            buffer.messages.push_back(message.to_vec());
+            true
+        });
+        if sent {
+            return Ok(());
+        }
+        Self::wait(time_out)
+    }
+
+    unsafe fn receive_buffer(
+        buffer_id: BufferId,
+        time_out: ApexSystemTime,
+        message: &mut [ApexByte],
+    ) -> Result<MessageSize, ErrorReturnCode> {
+        let Some(received) =
+            STATE.with_borrow_mut(|s| s.buffers[Self::index(buffer_id)].messages.pop_front())
+        else {
+            return Self::wait(time_out).map(|_| 0);
+        };
+        message[..received.len()].copy_from_slice(&received);
+        Ok(received.len() as MessageSize)
+    }
+
+    fn get_buffer_id(_buffer_name: BufferName) -> Result<BufferId, ErrorReturnCode> {
+        unimplemented!()
+    }
+
+    fn get_buffer_status(buffer_id: BufferId) -> Result<BufferStatus, ErrorReturnCode> {
+        Ok(STATE.with_borrow(|s| {
+            let buffer = &s.buffers[Self::index(buffer_id)];
+            BufferStatus {
+                nb_message: buffer.messages.len() as MessageRange,
+                max_nb_message: buffer.max_nb_message,
+                max_message_size: buffer.max_message_size,
+                waiting_processes: 0,
+            }
+        }))
+    }
+}
@@ -14,8 +14,14 @@ pub mod interrupt;
 pub mod limits;
 /// ARINC653P2-4 3.5
 pub mod logbook;
+/// Backend of the `log` crate
+#[cfg(feature = "log")]
+pub mod logger;
 /// ARINC653P2-4 3.9
 pub mod memory_block;
+/// Hypervisor for unit tests
+#[cfg(test)]
+pub(crate) mod mock;
 /// ARINC653P2-4 3.12
 pub mod multicore;
 /// ARINC653P1-5 3.7.2.5
@@ -42,5 +48,7 @@ pub mod semaphore;
 pub mod sp_data;
 /// ARINC653P1-5 3.4.2
 pub mod time;
+/// Software timers built on processes, events, mutexes and buffers
+pub mod timer;
 /// ARINC653 specific types
 pub mod types;
@@ -76,21 +76,38 @@ pub mod basic {
             time_out: ApexSystemTime,
         ) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: mutex with given `mutex_id` does not exist in this partition
+        /// - [ErrorReturnCode::InvalidParam]: `mutex_id` is [PREEMPTION_LOCK_MUTEX]
+        /// - [ErrorReturnCode::InvalidMode]: the given mutex is not owned by this process
         fn release_mutex(mutex_id: MutexId) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: mutex with given `mutex_id` does not exist in this partition
+        /// - [ErrorReturnCode::InvalidParam]: `mutex_id` is [PREEMPTION_LOCK_MUTEX]
+        /// - [ErrorReturnCode::InvalidParam]: process with given `process_id` does not exist in this partition
+        /// - [ErrorReturnCode::InvalidMode]: the given mutex is not owned by the process with `process_id`
         fn reset_mutex(mutex_id: MutexId, process_id: ProcessId) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: mutex with given `mutex_name` does not exist in this partition
         fn get_mutex_id(mutex_name: MutexName) -> Result<MutexId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: mutex with given `mutex_id` does not exist in this partition
         fn get_mutex_status(mutex_id: MutexId) -> Result<MutexStatus, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with given `process_id` does not exist in this partition
         fn get_process_mutex_state(process_id: ProcessId) -> Result<MutexId, ErrorReturnCode>;
     }
 }
 
 /// abstractions for ARINC653P1-5 3.7.2.5 mutex
 pub mod abstraction {
+    use core::cell::UnsafeCell;
     use core::marker::PhantomData;
+    use core::ops::{Deref, DerefMut};
     use core::sync::atomic::AtomicPtr;
 
     use super::basic::{ApexMutexP1, NO_MUTEX_OWNED, PREEMPTION_LOCK_MUTEX};
@@ -136,6 +153,8 @@ pub mod abstraction {
     }
 
     pub trait ApexMutexP1Ext: ApexMutexP1 + Sized {
+        /// # Errors
+        /// - [Error::InvalidConfig]: mutex with `name` does not exist
         fn get_mutex(name: Name) -> Result<Mutex<Self>, Error>;
     }
 
@@ -145,8 +164,9 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.7.2.5.6 this can only fail if the mutex_id
             //  does not exist in the current partition.
             // But since we retrieve the mutex_id directly from the hypervisor
-            //  there is no possible way for it not existing
-            let status = M::get_mutex_status(id).unwrap();
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
+            let status = M::get_mutex_status(id)?;
 
             Ok(Mutex {
                 _b: Default::default(),
@@ -157,6 +177,8 @@ pub mod abstraction {
     }
 
     impl<M: ApexMutexP1> Mutex<M> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: mutex with `name` does not exist
         pub fn from_name(name: Name) -> Result<Mutex<M>, Error> {
             M::get_mutex(name)
         }
@@ -169,41 +191,219 @@ pub mod abstraction {
             self.priority
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: different mutex is already held by this process
+        /// - [Error::InvalidMode]: this process is the error handler
+        /// - [Error::InvalidMode]: the priority of this process is greater than the priority of this mutex
+        /// - [Error::NotAvailable]: `timeout` is instant AND this mutex is owned by another process
+        /// - [Error::TimedOut]: `timeout` elapsed
+        /// - [Error::InvalidConfig]: lock count of this mutex is at [MAX_LOCK_LEVEL]
         pub fn acquire(&self, timeout: SystemTime) -> Result<(), Error> {
             M::acquire_mutex(self.id, timeout.into())?;
             Ok(())
         }
 
+        /// [Mutex::acquire] with this mutex as context of the error
+        ///
+        /// # Errors
+        /// - errors of [Mutex::acquire]
+        pub fn acquire_ctx(&self, timeout: SystemTime) -> Result<(), ContextError> {
+            M::acquire_mutex(self.id, timeout.into()).context(Operation::MutexAcquire, self.id)
+        }
+
+        /// # Errors
+        /// - [Error::InvalidMode]: this mutex is not owned by this process
         pub fn release(&self) -> Result<(), Error> {
             M::release_mutex(self.id)?;
             Ok(())
         }
 
+        /// [Mutex::release] with this mutex as context of the error
+        ///
+        /// # Errors
+        /// - errors of [Mutex::release]
+        pub fn release_ctx(&self) -> Result<(), ContextError> {
+            M::release_mutex(self.id).context(Operation::MutexRelease, self.id)
+        }
+
+        /// [acquire](Self::acquire) this mutex, [release](Self::release) it when the returned guard is dropped
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: different mutex is already held by this process
+        /// - [Error::InvalidMode]: this process is the error handler
+        /// - [Error::InvalidMode]: the priority of this process is greater than the priority of this mutex
+        /// - [Error::NotAvailable]: `timeout` is instant AND this mutex is owned by another process
+        /// - [Error::TimedOut]: `timeout` elapsed
+        /// - [Error::InvalidConfig]: lock count of this mutex is at [MAX_LOCK_LEVEL]
+        pub fn lock(&self, timeout: SystemTime) -> Result<MutexGuard<'_, M>, Error> {
+            self.acquire(timeout)?;
+            Ok(MutexGuard {
+                mutex: self,
+                _not_send: Default::default(),
+            })
+        }
+
+        /// # Errors
+        /// - [Error::InvalidParam]: `process` does not exist in this partition
+        /// - [Error::InvalidMode]: this mutex is not owned by `process`
         pub fn reset(&self, process: &Process<M>) -> Result<(), Error> {
             M::reset_mutex(self.id, process.id())?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this mutex does not exist anymore
+        pub fn try_status(&self) -> Result<MutexStatus, Error> {
+            Ok(M::get_mutex_status(self.id)?)
+        }
+
+        /// # Panics
+        /// if this mutex does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> MutexStatus {
             // According to ARINC653P1-5 3.7.2.5.6 this can only fail if the mutex_id
             //  does not exist in the current partition.
             // But since we retrieve the mutex_id directly from the hypervisor
             //  there is no possible way for it not existing
-            M::get_mutex_status(self.id).unwrap()
+            self.try_status().unwrap()
+        }
+    }
+
+    /// Lock of a [Mutex], releasing the mutex on drop
+    #[must_use = "the mutex is released immediately if the guard is dropped"]
+    #[derive(Debug)]
+    pub struct MutexGuard<'a, M: ApexMutexP1> {
+        mutex: &'a Mutex<M>,
+        // The mutex is owned by the locking process
+        _not_send: PhantomData<*const ()>,
+    }
+
+    impl<M: ApexMutexP1> Drop for MutexGuard<'_, M> {
+        fn drop(&mut self) {
+            // Can not fail, as the mutex was acquired by this guard in the same process
+            let _ = self.mutex.release();
+        }
+    }
+
+    /// Value protected by a [Mutex], only accessible while the mutex is locked
+    ///
+    /// Unlike the ARINC653 mutex itself, locking is not recursive,
+    /// as this would hand out multiple mutable references to the value.
+    /// The [Mutex] is not accessible, so it can not be released or reset while the value is borrowed.
+    pub struct ApexMutex<M: ApexMutexP1, T> {
+        mutex: Mutex<M>,
+        value: UnsafeCell<T>,
+    }
+
+    // Access to the value is serialized by the mutex,
+    //  which is only used by this ApexMutex as required by `ApexMutex::new`
+    unsafe impl<M: ApexMutexP1, T: Send> Sync for ApexMutex<M, T> {}
+
+    impl<M: ApexMutexP1, T> ApexMutex<M, T> {
+        /// # Safety
+        /// `mutex` must only be used through the returned [ApexMutex].
+        /// No clone of it may exist and it must not be obtained elsewhere,
+        /// e.g. by [Mutex::from_name] or [ApexMutexP1Ext::get_mutex],
+        /// as releasing or resetting it would give another process access to the borrowed value.
+        pub unsafe fn new(mutex: Mutex<M>, value: T) -> Self {
+            Self {
+                mutex,
+                value: UnsafeCell::new(value),
+            }
+        }
+
+        /// # Errors
+        /// - [Error::InvalidMode]: this mutex is already locked by this process
+        /// - [Error::InvalidParam]: the mutex does not exist anymore
+        /// - errors of [Mutex::lock]
+        pub fn lock(&self, timeout: SystemTime) -> Result<ApexMutexGuard<'_, M, T>, Error> {
+            let guard = self.mutex.lock(timeout)?;
+            if self.mutex.try_status()?.lock_count > 1 {
+                return Err(Error::InvalidMode);
+            }
+            Ok(ApexMutexGuard {
+                guard,
+                value: &self.value,
+            })
+        }
+
+        pub fn into_inner(self) -> T {
+            self.value.into_inner()
+        }
+
+        /// Mutable access without locking, as `self` is borrowed exclusively
+        pub fn get_mut(&mut self) -> &mut T {
+            self.value.get_mut()
+        }
+    }
+
+    impl<M: ApexMutexP1, T: core::fmt::Debug> core::fmt::Debug for ApexMutex<M, T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("ApexMutex")
+                .field("mutex", &self.mutex.id())
+                .finish_non_exhaustive()
+        }
+    }
+
+    /// Lock of an [ApexMutex], giving access to its value
+    #[must_use = "the mutex is released immediately if the guard is dropped"]
+    pub struct ApexMutexGuard<'a, M: ApexMutexP1, T> {
+        guard: MutexGuard<'a, M>,
+        value: &'a UnsafeCell<T>,
+    }
+
+    impl<M: ApexMutexP1, T> Deref for ApexMutexGuard<'_, M, T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            // This is safe because the mutex is held exactly once by this guard
+            unsafe { &*self.value.get() }
+        }
+    }
+
+    impl<M: ApexMutexP1, T> DerefMut for ApexMutexGuard<'_, M, T> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            // This is safe because the mutex is held exactly once by this guard
+            unsafe { &mut *self.value.get() }
+        }
+    }
+
+    impl<M: ApexMutexP1, T: core::fmt::Debug> core::fmt::Debug for ApexMutexGuard<'_, M, T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("ApexMutexGuard")
+                .field("mutex", &self.guard.mutex.id())
+                .field("value", &**self)
+                .finish()
         }
     }
 
     impl<A: ApexMutexP1 + ApexProcessP4> Process<A> {
+        /// # Errors
+        /// - [Error::InvalidParam]: this process does not exist anymore
+        pub fn try_get_process_mutex_state(&self) -> Result<MutexOwnedStatus, Error> {
+            Ok(A::get_process_mutex_state(self.id())?.into())
+        }
+
+        /// # Panics
+        /// if this process does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn get_process_mutex_state(&self) -> MutexOwnedStatus {
             // According to ARINC653P1-5 3.7.2.5.7 this can only fail if the process_id
             //  does not exist in the current partition.
             // But since we retrieve the process_id directly from the hypervisor
             //  there is no possible way for it not existing
-            A::get_process_mutex_state(self.id()).unwrap().into()
+            self.try_get_process_mutex_state().unwrap()
         }
     }
 
     impl<M: ApexMutexP1> StartContext<M> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_MUTEXES](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_MUTEXES) was reached
+        /// - [Error::NoAction]: a mutex with given `name` already exists in this partition
+        /// - [Error::InvalidParam]: `priority` is invalid
+        /// - [Error::InvalidParam]: `qd` is unsupported
         pub fn create_mutex(
             &mut self,
             name: Name,
@@ -79,16 +79,24 @@ pub mod basic {
         // As stated in ARINC653P1-5 3.2.2.1, this never fails
         fn get_partition_status() -> ApexPartitionStatus;
 
+        /// # Errors
+        /// - [ErrorReturnCode::NoAction]: `operating_mode` is [OperatingMode::Normal] and partition mode is [OperatingMode::Normal]
+        /// - [ErrorReturnCode::InvalidMode]: `operating_mode` is [OperatingMode::WarmStart] and partition mode is [OperatingMode::ColdStart]
         fn set_partition_mode(operating_mode: OperatingMode) -> Result<(), ErrorReturnCode>;
     }
 }
 
 /// abstraction for ARINC653P1-5 3.2.2 partition
 pub mod abstraction {
+    use core::cell::UnsafeCell;
+    use core::convert::Infallible;
     use core::marker::PhantomData;
-    use core::sync::atomic::AtomicPtr;
+    use core::mem::MaybeUninit;
+    use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 
     use super::basic::{ApexPartitionP4, ApexPartitionStatus};
+    use crate::apex::error::basic::ApexErrorP4;
+    use crate::apex::process::basic::ApexProcessP1;
     // Reexport important basic-types for downstream-user
     pub use super::basic::{NumCores, OperatingMode, PartitionId, StartCondition};
     use crate::prelude::*;
@@ -119,26 +127,413 @@ pub mod abstraction {
         }
     }
 
+    /// Static description of the resources declared by a partition
+    ///
+    /// Generated by the `partition` macro as `MANIFEST`,
+    /// so that resources can be listed without repeating their declarations.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct PartitionManifest {
+        pub channels: &'static [ChannelManifest],
+        pub processes: &'static [ProcessManifest],
+    }
+
+    impl PartitionManifest {
+        pub fn channel(&self, name: &Name) -> Option<&ChannelManifest> {
+            self.channels.iter().find(|c| &c.name == name)
+        }
+
+        /// Finds the process declaration creating the process `name`
+        pub fn process(&self, name: &Name) -> Option<&ProcessManifest> {
+            self.processes.iter().find(|p| p.names.contains(name))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct ChannelManifest {
+        pub name: Name,
+        pub msg_size: MessageSize,
+        pub kind: ChannelKind,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub enum ChannelKind {
+        SamplingSource,
+        SamplingDestination {
+            refresh_period: core::time::Duration,
+        },
+        QueuingSender {
+            nb_msgs: MessageRange,
+            discipline: QueuingDiscipline,
+        },
+        QueuingReceiver {
+            nb_msgs: MessageRange,
+            discipline: QueuingDiscipline,
+        },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct ProcessManifest {
+        /// Names of all processes created from this declaration, one per instance
+        pub names: &'static [Name],
+        /// [SystemTime::Infinite] for aperiodic processes
+        pub period: SystemTime,
+        pub time_capacity: SystemTime,
+        pub base_priority: Priority,
+        pub stack_size: StackSize,
+        pub deadline: Deadline,
+    }
+
+    /// Reason for the partition start failing
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum StartError {
+        /// The partition was neither in [OperatingMode::ColdStart] nor [OperatingMode::WarmStart]
+        UnexpectedMode(OperatingMode),
+        /// Setting the partition mode to [OperatingMode::Normal] after the start failed
+        NormalMode(Error),
+        /// The resource with this name is required, but was not created during the start
+        MissingRequired(Name),
+        /// The start succeeded, but idling afterwards failed, see [IdlePolicy]
+        Idle(Error),
+    }
+
+    impl StartError {
+        /// Message reported by [StartFailurePolicy]
+        pub fn message(&self) -> &'static str {
+            match self {
+                StartError::UnexpectedMode(_) => "partition start in unexpected operating mode",
+                StartError::NormalMode(_) => "partition failed to enter normal operating mode",
+                StartError::MissingRequired(_) => "required resource was not created during start",
+                StartError::Idle(_) => "initialization process failed to idle after start",
+            }
+        }
+    }
+
+    /// Reaction of [PartitionExt::try_run] on a [StartError]
+    ///
+    /// In any case, the [StartError::message] is reported via [ApexErrorP4Ext::report_application_message] first.
+    /// If the partition mode can not be changed, this is reported as well.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum StartFailurePolicy {
+        /// Return the error to the caller
+        #[default]
+        Report,
+        /// Set the partition mode to [OperatingMode::Idle]
+        Idle,
+        /// Restart the partition by setting the partition mode to [OperatingMode::WarmStart].
+        /// A failed cold start is restarted with [OperatingMode::ColdStart] instead,
+        /// as ARINC653 rejects a warm start during a cold start.
+        WarmStart,
+    }
+
+    /// Behavior of the initialization process after the partition start
+    ///
+    /// ARINC653 defines `TIMED_WAIT` and `STOP_SELF` for processes only.
+    /// Hence [IdlePolicy::TimedWait] and [IdlePolicy::StopSelf] require a hypervisor
+    /// supporting them for the initialization process as well,
+    /// otherwise [IdlePolicy::idle] fails.
+    #[derive(Debug, Clone, Copy)]
+    pub enum IdlePolicy {
+        /// Busy loop, like [PartitionExt::run]
+        Spin,
+        /// Repeatedly wait for the given duration, requires hypervisor support
+        TimedWait(core::time::Duration),
+        /// Stop the initialization process, requires hypervisor support
+        StopSelf,
+        /// Hypervisor specific yield, called repeatedly
+        Yield(fn()),
+    }
+
+    impl IdlePolicy {
+        /// Idle once according to this policy, meant to be used with [PartitionExt::run_with_idle]
+        ///
+        /// # Errors
+        /// - [Error::InvalidMode]: [IdlePolicy::StopSelf] returned, as the hypervisor does not support it
+        /// - errors of [ApexTimeP1Ext::timed_wait] for [IdlePolicy::TimedWait]
+        pub fn idle<H: ApexTimeP1Ext + ApexProcessP1>(&self) -> Result<(), Error> {
+            match self {
+                IdlePolicy::Spin => core::hint::spin_loop(),
+                IdlePolicy::TimedWait(delay) => <H as ApexTimeP1Ext>::timed_wait(*delay)?,
+                IdlePolicy::StopSelf => {
+                    H::stop_self();
+                    // Stopping the initialization process never returns, if supported
+                    return Err(Error::InvalidMode);
+                }
+                IdlePolicy::Yield(yield_fn) => yield_fn(),
+            }
+            Ok(())
+        }
+    }
+
     #[derive(Debug)]
     pub struct StartContext<A> {
         _a: PhantomData<AtomicPtr<A>>,
     }
 
+    impl<A: ApexPartitionP4> StartContext<A> {
+        /// Reason for the current cold or warm start
+        pub fn start_condition(&self) -> StartCondition {
+            A::get_partition_status().start_condition
+        }
+
+        /// Finish the start by setting the partition mode to [OperatingMode::Normal]
+        ///
+        /// Consuming the context ensures that nothing can be created afterwards.
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: partition mode is already [OperatingMode::Normal]
+        pub fn into_normal(self) -> Result<NormalContext<A>, Error> {
+            A::set_partition_mode(<mode::Normal as mode::Mode>::MODE)?;
+            Ok(NormalContext {
+                _a: Default::default(),
+            })
+        }
+    }
+
+    /// Typestates of the [OperatingMode]
+    ///
+    /// [mode::ColdStart] and [mode::WarmStart] are represented by a [StartContext],
+    /// [mode::Normal] by a [NormalContext].
+    /// [mode::Normal] can only be entered by consuming the [StartContext] with [StartContext::into_normal],
+    /// while all other modes may be requested from anywhere as [mode::Restart].
+    pub mod mode {
+        use crate::prelude::OperatingMode;
+
+        mod sealed {
+            pub trait Sealed {}
+        }
+
+        pub trait Mode: sealed::Sealed {
+            const MODE: OperatingMode;
+        }
+
+        /// Modes which can be set at any time, stopping or restarting the partition
+        pub trait Restart: Mode {}
+
+        #[derive(Debug, Clone, Copy)]
+        pub struct Idle;
+        #[derive(Debug, Clone, Copy)]
+        pub struct ColdStart;
+        #[derive(Debug, Clone, Copy)]
+        pub struct WarmStart;
+        #[derive(Debug, Clone, Copy)]
+        pub struct Normal;
+
+        macro_rules! impl_mode {
+            ($($mode:ident),*) => {$(
+                impl sealed::Sealed for $mode {}
+                impl Mode for $mode {
+                    const MODE: OperatingMode = OperatingMode::$mode;
+                }
+            )*};
+        }
+        impl_mode!(Idle, ColdStart, WarmStart, Normal);
+
+        impl Restart for Idle {}
+        impl Restart for ColdStart {}
+        impl Restart for WarmStart {}
+    }
+
+    /// Proof of the partition being in [OperatingMode::Normal], returned by [PartitionExt::start_on]
+    #[derive(Debug)]
+    pub struct NormalContext<A> {
+        _a: PhantomData<AtomicPtr<A>>,
+    }
+
+    impl<A: ApexPartitionP4> NormalContext<A> {
+        /// Stop or restart the partition, consuming this context
+        ///
+        /// # Errors
+        /// - [Error::InvalidMode]: the requested mode was rejected by the hypervisor
+        pub fn restart<M: mode::Restart>(self) -> Result<(), Error> {
+            A::set_partition_mode(M::MODE)?;
+            Ok(())
+        }
+    }
+
+    impl<A> StartContext<A> {
+        /// Create a context for starting a partition via [PartitionExt::start_on]
+        ///
+        /// # Safety
+        /// The context must be unique and only exist while the partition is in
+        /// [OperatingMode::ColdStart] or [OperatingMode::WarmStart],
+        /// as values initialized through it are assumed not to be observed by running processes.
+        pub unsafe fn new() -> Self {
+            StartContext {
+                _a: Default::default(),
+            }
+        }
+    }
+
+    /// Cell for values initialized during the partition start and only read afterwards.
+    ///
+    /// Setting the value requires the [StartContext],
+    /// which only exists while the partition is in cold or warm start.
+    /// As no process is running during this time, no reader can observe a partially initialized value.
+    pub struct StartCell<T> {
+        initialized: AtomicBool,
+        value: UnsafeCell<MaybeUninit<T>>,
+    }
+
+    // The value is only written through the unique StartContext, before `initialized` is set,
+    //  and only dropped by `reset`, whose caller guarantees that no reference is alive
+    unsafe impl<T: Send + Sync> Sync for StartCell<T> {}
+
+    impl<T> StartCell<T> {
+        pub const fn new() -> Self {
+            Self {
+                initialized: AtomicBool::new(false),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            }
+        }
+
+        /// Initialize this cell with `value`, returning a reference to the stored value
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: this cell was already initialized
+        pub fn set<A>(&self, _ctx: &mut StartContext<A>, value: T) -> Result<&T, Error> {
+            if self.initialized.load(Ordering::Acquire) {
+                return Err(Error::NoAction);
+            }
+            // This is safe because the StartContext is unique and the cell was not initialized yet,
+            //  so there are neither readers nor other writers
+            unsafe { (*self.value.get()).write(value) };
+            self.initialized.store(true, Ordering::Release);
+            self.get()
+        }
+
+        /// Drop the value of this cell, so it can be set again during a warm start
+        ///
+        /// # Safety
+        /// No reference obtained from this cell may be alive,
+        /// neither from [StartCell::set] nor from [StartCell::get] or [StartCell::get_all].
+        /// This includes references stored in other `static`s.
+        pub unsafe fn reset<A>(&self, _ctx: &mut StartContext<A>) {
+            if self.initialized.swap(false, Ordering::AcqRel) {
+                // This is safe because the value was initialized and the caller guarantees
+                //  that no reference to it is alive
+                unsafe { (*self.value.get()).assume_init_drop() };
+            }
+        }
+
+        /// # Errors
+        /// - [Error::Uninitialized]: this cell was not initialized during the partition start
+        pub fn get(&self) -> Result<&T, Error> {
+            if !self.initialized.load(Ordering::Acquire) {
+                return Err(Error::Uninitialized);
+            }
+            // This is safe because the value was initialized and is only dropped by `reset`,
+            //  whose caller guarantees that no reference is alive
+            Ok(unsafe { (*self.value.get()).assume_init_ref() })
+        }
+
+        pub fn is_initialized(&self) -> bool {
+            self.initialized.load(Ordering::Acquire)
+        }
+
+        /// Get the values of all `cells` at once
+        ///
+        /// # Errors
+        /// - [Error::Uninitialized]: any of the `cells` was not initialized during the partition start
+        pub fn get_all<const N: usize>(cells: &[Self; N]) -> Result<[&T; N], Error> {
+            if !cells.iter().all(Self::is_initialized) {
+                return Err(Error::Uninitialized);
+            }
+            // This is safe because all values were initialized and are only dropped by `reset`
+            Ok(cells
+                .each_ref()
+                .map(|cell| unsafe { (*cell.value.get()).assume_init_ref() }))
+        }
+    }
+
+    impl<T> Default for StartCell<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T: core::fmt::Debug> core::fmt::Debug for StartCell<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_tuple("StartCell").field(&self.get().ok()).finish()
+        }
+    }
+
+    impl<T> Drop for StartCell<T> {
+        fn drop(&mut self) {
+            if *self.initialized.get_mut() {
+                // This is safe because the value was initialized
+                unsafe { self.value.get_mut().assume_init_drop() };
+            }
+        }
+    }
+
     pub trait PartitionExt<A>: Partition<A>
     where
         A: ApexPartitionP4,
     {
         fn get_status() -> PartitionStatus;
 
-        /// change partition mode  
-        /// DO NOT CALL THIS WITH [OperatingMode::Normal] FROM THE START FUNCTION.
+        /// change partition mode to `M`, stopping or restarting the partition
+        ///
+        /// [OperatingMode::Normal] can only be entered via [StartContext::into_normal].
+        ///
+        /// # Errors
+        /// - [Error::InvalidMode]: `M` is [mode::WarmStart] and partition mode is [OperatingMode::ColdStart]
+        fn set_mode<M: mode::Restart>() -> Result<(), Error>;
+
+        /// Run the cold or warm start and set the partition mode to [OperatingMode::Normal].
+        ///
+        /// Unlike [PartitionExt::run], this returns afterwards.
+        /// This allows hosting several partitions in one binary, i.e. for tests against a simulated module.
+        ///
+        /// # Panics
+        /// if the partition is neither in [OperatingMode::ColdStart] nor [OperatingMode::WarmStart]
+        fn start_on(&self, ctx: StartContext<A>) -> NormalContext<A>;
+
+        /// Like [PartitionExt::start_on], but returning an error instead of panicking
         ///
         /// # Errors
-        /// - [Error::NoAction]: `mode` is [OperatingMode::Normal] and partition mode is [OperatingMode::Normal]
-        /// - [Error::InvalidMode]: `mode` is [OperatingMode::WarmStart] and partition mode is [OperatingMode::ColdStart]
-        fn set_mode(mode: OperatingMode) -> Result<(), Error>;
+        /// - [StartError::UnexpectedMode]: the partition is neither in [OperatingMode::ColdStart] nor [OperatingMode::WarmStart]
+        /// - [StartError::MissingRequired]: see [Partition::check_required]
+        /// - [StartError::NormalMode]: the partition mode could not be set to [OperatingMode::Normal]
+        fn try_start_on(&self, ctx: StartContext<A>) -> Result<NormalContext<A>, StartError>;
 
+        /// Start the partition and busy loop afterwards
         fn run(self) -> !;
+
+        /// Start the partition and call `idle` repeatedly afterwards, until it fails
+        ///
+        /// Hypervisors returning control to the initialization process after the start
+        /// may use this for not wasting the partition window, i.e. with [IdlePolicy::idle].
+        ///
+        /// # Errors
+        /// - errors of `idle`
+        ///
+        /// # Panics
+        /// if the start fails, see [PartitionExt::start_on]
+        fn run_with_idle(
+            self,
+            idle: impl FnMut() -> Result<(), Error>,
+        ) -> Result<Infallible, Error>;
+
+        /// Like [PartitionExt::run_with_idle], but handling a failed start according to `policy`
+        ///
+        /// Only returns if the start failed and `policy` did not change the partition mode.
+        ///
+        /// # Errors
+        /// - [StartError]: the start failed, see [PartitionExt::try_start_on]
+        /// - [StartError::Idle]: `idle` failed after a successful start, which is not handled by `policy`
+        fn try_run(
+            self,
+            policy: StartFailurePolicy,
+            idle: impl FnMut() -> Result<(), Error>,
+        ) -> Result<Infallible, StartError>
+        where
+            A: ApexErrorP4;
     }
 
     impl<A, P> PartitionExt<A> for P
@@ -150,31 +545,90 @@ pub mod abstraction {
             A::get_partition_status().into()
         }
 
-        fn set_mode(mode: OperatingMode) -> Result<(), Error> {
-            Ok(A::set_partition_mode(mode)?)
+        fn set_mode<M: mode::Restart>() -> Result<(), Error> {
+            Ok(A::set_partition_mode(M::MODE)?)
         }
 
-        fn run(self) -> ! {
-            let mut ctx = StartContext {
-                _a: Default::default(),
-            };
+        fn start_on(&self, ctx: StartContext<A>) -> NormalContext<A> {
+            match self.try_start_on(ctx) {
+                Ok(normal) => normal,
+                Err(e) => panic!("{e:?}"),
+            }
+        }
+
+        fn try_start_on(&self, mut ctx: StartContext<A>) -> Result<NormalContext<A>, StartError> {
             let status = Self::get_status();
 
             match status.operating_mode {
                 OperatingMode::ColdStart => self.cold_start(&mut ctx),
                 OperatingMode::WarmStart => self.warm_start(&mut ctx),
-                // As per ARINC653P1-5 Figure 2.3.1.4, this can not happen
-                unexpected => panic!("{unexpected:?}"),
+                // As per ARINC653P1-5 Figure 2.3.1.4, this can only happen if the start is repeated
+                unexpected => return Err(StartError::UnexpectedMode(unexpected)),
             };
+            self.check_required()?;
 
             // As stated in ARINC653P1-5 3.2.2.2, this can not fail,
             // because we are either in COLD_START or WARM_START
-            A::set_partition_mode(OperatingMode::Normal).unwrap();
+            ctx.into_normal().map_err(StartError::NormalMode)
+        }
+
+        fn run(self) -> ! {
+            // This is safe because the context is created only once, before any process runs
+            let ctx = unsafe { StartContext::new() };
+            self.start_on(ctx);
 
-            #[allow(clippy::empty_loop)]
             loop {
-                //Verify
+                core::hint::spin_loop()
+            }
+        }
+
+        fn run_with_idle(
+            self,
+            mut idle: impl FnMut() -> Result<(), Error>,
+        ) -> Result<Infallible, Error> {
+            // This is safe because the context is created only once, before any process runs
+            let ctx = unsafe { StartContext::new() };
+            self.start_on(ctx);
+
+            loop {
+                idle()?
+            }
+        }
+
+        fn try_run(
+            self,
+            policy: StartFailurePolicy,
+            mut idle: impl FnMut() -> Result<(), Error>,
+        ) -> Result<Infallible, StartError>
+        where
+            A: ApexErrorP4,
+        {
+            // This is safe because the context is created only once, before any process runs
+            let ctx = unsafe { StartContext::new() };
+            let Err(e) = self.try_start_on(ctx) else {
+                loop {
+                    idle().map_err(StartError::Idle)?
+                }
+            };
+
+            // Reporting is best effort, the start error is more relevant
+            let _ = <A as ApexErrorP4Ext>::report_application_message(e.message().as_bytes());
+            let restart = match policy {
+                StartFailurePolicy::Report => Ok(()),
+                StartFailurePolicy::Idle => Self::set_mode::<mode::Idle>(),
+                StartFailurePolicy::WarmStart
+                    if Self::get_status().operating_mode == OperatingMode::ColdStart =>
+                {
+                    Self::set_mode::<mode::ColdStart>()
+                }
+                StartFailurePolicy::WarmStart => Self::set_mode::<mode::WarmStart>(),
+            };
+            if restart.is_err() {
+                let _ = <A as ApexErrorP4Ext>::report_application_message(
+                    b"partition mode could not be changed after a failed start",
+                );
             }
+            Err(e)
         }
     }
 
@@ -184,5 +638,13 @@ pub mod abstraction {
     {
         fn cold_start(&self, ctx: &mut StartContext<P>);
         fn warm_start(&self, ctx: &mut StartContext<P>);
+
+        /// Check for all required resources having been created, called after the cold or warm start
+        ///
+        /// # Errors
+        /// - [StartError::MissingRequired]: a required resource was not created
+        fn check_required(&self) -> Result<(), StartError> {
+            Ok(())
+        }
     }
 }
@@ -57,6 +57,7 @@ pub mod basic {
 
     /// ARINC653P1-5 3.3.1
     #[repr(C)]
+    #[allow(unpredictable_function_pointer_comparisons)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct ApexProcessAttribute {
         pub period: ApexSystemTime,
@@ -95,37 +96,89 @@ pub mod basic {
         /// - [ErrorReturnCode::InvalidMode]: our current operating mode is [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
         fn create_process(attributes: &ApexProcessAttribute) -> Result<ProcessId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist
+        /// - [ErrorReturnCode::NoAction]: process with `process_id` is not dormant
+        /// - [ErrorReturnCode::InvalidConfig]: deadline calculation failed
         fn start(process_id: ProcessId) -> Result<(), ErrorReturnCode>;
     }
 
     pub trait ApexProcessP1: ApexProcessP4 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `priority` is invalid
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` is dormant
         fn set_priority(process_id: ProcessId, priority: Priority) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidMode]: calling process holds a mutex
+        /// - [ErrorReturnCode::InvalidMode]: calling process is error handler
+        /// - [ErrorReturnCode::InvalidMode]: calling process is periodic
+        /// - [ErrorReturnCode::InvalidParam]: `time_out` is invalid
+        /// - [ErrorReturnCode::TimedOut]: `time_out` elapsed
         fn suspend_self(time_out: ApexSystemTime) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist or is the calling process
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` holds a mutex or is waiting on one
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` is dormant or faulted
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` is periodic
+        /// - [ErrorReturnCode::NoAction]: process with `process_id` is already suspended
         fn suspend(process_id: ProcessId) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist or is the calling process
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` is dormant or faulted
+        /// - [ErrorReturnCode::InvalidMode]: process with `process_id` is periodic
+        /// - [ErrorReturnCode::NoAction]: process with `process_id` is not suspended
         fn resume(process_id: ProcessId) -> Result<(), ErrorReturnCode>;
 
         fn stop_self();
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist or is the calling process
+        /// - [ErrorReturnCode::NoAction]: process with `process_id` is already dormant
         fn stop(process_id: ProcessId) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `delay_time` is invalid
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` is periodic and `delay_time` is not less than its period
+        /// - [ErrorReturnCode::NoAction]: process with `process_id` is not dormant
+        /// - [ErrorReturnCode::InvalidConfig]: deadline calculation failed
         fn delayed_start(
             process_id: ProcessId,
             delay_time: ApexSystemTime,
         ) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::NoAction]: calling process is error handler
+        /// - [ErrorReturnCode::NoAction]: our current operating mode is not [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
+        /// - [ErrorReturnCode::InvalidConfig]: lock level is at [MAX_LOCK_LEVEL]
         fn lock_preemption() -> Result<LockLevel, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::NoAction]: calling process is error handler
+        /// - [ErrorReturnCode::NoAction]: our current operating mode is not [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
+        /// - [ErrorReturnCode::NoAction]: lock level is [MIN_LOCK_LEVEL]
         fn unlock_preemption() -> Result<LockLevel, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidMode]: caller is not a process (i.e. the partition main thread)
         fn get_my_id() -> Result<ProcessId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: process with `process_name` does not exist
         fn get_process_id(process_name: ProcessName) -> Result<ProcessId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist
         fn get_process_status(process_id: ProcessId) -> Result<ApexProcessStatus, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: process with `process_id` does not exist
+        /// - [ErrorReturnCode::InvalidConfig]: `processor_core_id` is not assigned to this partition
+        /// - [ErrorReturnCode::InvalidMode]: our current operating mode is [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
         // Only during Warm/Cold-Start
         fn initialize_process_core_affinity(
             process_id: ProcessId,
@@ -134,6 +187,8 @@ pub mod basic {
 
         fn get_my_processor_core_id() -> ProcessorCoreId;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidMode]: caller is not a process (i.e. the partition main thread or error handler)
         fn get_my_index() -> Result<ProcessIndex, ErrorReturnCode>;
     }
 }
@@ -142,6 +197,7 @@ pub mod basic {
 pub mod abstraction {
     use core::marker::PhantomData;
     use core::sync::atomic::AtomicPtr;
+    use core::time::Duration;
 
     use super::basic::{ApexProcessAttribute, ApexProcessP1, ApexProcessP4, ApexProcessStatus};
     // Reexport important basic-types for downstream-user
@@ -150,8 +206,10 @@ pub mod abstraction {
         SystemAddress, MAIN_PROCESS_ID, MAX_LOCK_LEVEL, MAX_PRIORITY_VALUE, MIN_LOCK_LEVEL,
         MIN_PRIORITY_VALUE, NULL_PROCESS_ID,
     };
+    use crate::apex::error::abstraction::with_message;
     use crate::prelude::*;
 
+    #[allow(unpredictable_function_pointer_comparisons)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct ProcessAttribute {
         pub period: SystemTime,
@@ -226,6 +284,8 @@ pub mod abstraction {
     }
 
     pub trait ApexProcessP1Ext: ApexProcessP1 + Sized {
+        /// # Errors
+        /// - [Error::InvalidConfig]: process with `name` does not exist
         fn get_process(name: Name) -> Result<Process<Self>, Error>;
     }
 
@@ -239,7 +299,34 @@ pub mod abstraction {
         }
     }
 
+    /// Preemption lock of the calling process, unlocking preemption on drop
+    #[must_use = "preemption is unlocked immediately if the guard is dropped"]
+    #[derive(Debug)]
+    pub struct PreemptionGuard<P: ApexProcessP1> {
+        _p: PhantomData<AtomicPtr<P>>,
+        // The lock belongs to the calling process
+        _not_send: PhantomData<*const ()>,
+        level: LockLevel,
+    }
+
+    impl<P: ApexProcessP1> PreemptionGuard<P> {
+        /// Lock level after locking
+        pub fn level(&self) -> LockLevel {
+            self.level
+        }
+    }
+
+    impl<P: ApexProcessP1> Drop for PreemptionGuard<P> {
+        fn drop(&mut self) {
+            // Can not fail, as the lock level was raised by this guard in the same process
+            let _ = P::unlock_preemption();
+        }
+    }
+
     impl<P: ApexProcessP4> Process<P> {
+        /// # Errors
+        /// - [Error::NoAction]: this process is not dormant
+        /// - [Error::InvalidConfig]: deadline calculation failed
         pub fn start(&self) -> Result<(), Error> {
             P::start(self.id)?;
             Ok(())
@@ -251,10 +338,14 @@ pub mod abstraction {
     }
 
     impl<P: ApexProcessP1> Process<P> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: process with `name` does not exist
         pub fn from_name(name: Name) -> Result<Process<P>, Error> {
             P::get_process(name)
         }
 
+        /// # Errors
+        /// - [Error::InvalidMode]: caller is not a process (i.e. the partition main thread)
         pub fn get_self() -> Result<Process<P>, Error> {
             let id = P::get_my_id()?;
             Ok(Process {
@@ -263,21 +354,41 @@ pub mod abstraction {
             })
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: `priority` is invalid
+        /// - [Error::InvalidMode]: this process is dormant
         pub fn set_priority(&self, priority: Priority) -> Result<(), Error> {
             P::set_priority(self.id, priority)?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::InvalidMode]: calling process holds a mutex
+        /// - [Error::InvalidMode]: calling process is error handler
+        /// - [Error::InvalidMode]: calling process is periodic
+        /// - [Error::InvalidParam]: `time_out` is invalid
+        /// - [Error::TimedOut]: `time_out` elapsed
         pub fn suspend_self(time_out: SystemTime) -> Result<(), Error> {
             P::suspend_self(time_out.into())?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this process is the calling process
+        /// - [Error::InvalidMode]: this process holds a mutex or is waiting on one
+        /// - [Error::InvalidMode]: this process is dormant or faulted
+        /// - [Error::InvalidMode]: this process is periodic
+        /// - [Error::NoAction]: this process is already suspended
         pub fn suspend(&self) -> Result<(), Error> {
             P::suspend(self.id)?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this process is the calling process
+        /// - [Error::InvalidMode]: this process is dormant or faulted
+        /// - [Error::InvalidMode]: this process is periodic
+        /// - [Error::NoAction]: this process is not suspended
         pub fn resume(&self) -> Result<(), Error> {
             P::resume(self.id)?;
             Ok(())
@@ -287,42 +398,94 @@ pub mod abstraction {
             P::stop_self()
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this process is the calling process
+        /// - [Error::NoAction]: this process is already dormant
         pub fn stop(&self) -> Result<(), Error> {
             P::stop(self.id)?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: `delay_time` is invalid
+        /// - [Error::InvalidParam]: this process is periodic and `delay_time` is not less than its period
+        /// - [Error::NoAction]: this process is not dormant
+        /// - [Error::InvalidConfig]: deadline calculation failed
         pub fn delayed_start(&self, delay_time: SystemTime) -> Result<(), Error> {
             P::delayed_start(self.id, delay_time.into())?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::NoAction]: calling process is error handler
+        /// - [Error::NoAction]: our current operating mode is not [OperatingMode::Normal]
+        /// - [Error::InvalidConfig]: lock level is at [MAX_LOCK_LEVEL]
         pub fn lock_preemption() -> Result<LockLevel, Error> {
             Ok(P::lock_preemption()?)
         }
 
+        /// # Errors
+        /// - [Error::NoAction]: calling process is error handler
+        /// - [Error::NoAction]: our current operating mode is not [OperatingMode::Normal]
+        /// - [Error::NoAction]: lock level is [MIN_LOCK_LEVEL]
         pub fn unlock_preemption() -> Result<LockLevel, Error> {
             Ok(P::unlock_preemption()?)
         }
 
+        /// Lock preemption until the returned guard is dropped
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: calling process is error handler
+        /// - [Error::NoAction]: our current operating mode is not [OperatingMode::Normal]
+        /// - [Error::InvalidConfig]: lock level is at [MAX_LOCK_LEVEL]
+        pub fn preemption_lock() -> Result<PreemptionGuard<P>, Error> {
+            let level = Self::lock_preemption()?;
+            Ok(PreemptionGuard {
+                _p: Default::default(),
+                _not_send: Default::default(),
+                level,
+            })
+        }
+
+        /// # Errors
+        /// - [Error::InvalidParam]: this process does not exist anymore
+        pub fn try_status(&self) -> Result<ProcessStatus, Error> {
+            Ok(P::get_process_status(self.id)?.into())
+        }
+
+        /// # Panics
+        /// if this process does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> ProcessStatus {
             // According to ARINC653P1-5 3.3.2.2 this can only fail if the processId
             //  does not exist in the current partition.
             // But since we retrieve the processId directly from the hypervisor
             //  there is no possible way for it not existing
-            P::get_process_status(self.id).unwrap().into()
+            self.try_status().unwrap()
         }
 
         pub fn get_my_processor_core_id() -> ProcessorCoreId {
             P::get_my_processor_core_id()
         }
 
+        /// # Errors
+        /// - [Error::InvalidMode]: caller is not a process (i.e. the partition main thread or error handler)
         pub fn get_my_index() -> Result<ProcessIndex, Error> {
             Ok(P::get_my_index()?)
         }
     }
 
     impl<P: ApexProcessP4> StartContext<P> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_PROCESSES](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_PROCESSES) was reached
+        /// - [Error::NoAction]: a process with given `attr.name` already exists
+        /// - [Error::InvalidParam]: `attr.stack_size` is invalid
+        /// - [Error::InvalidParam]: `attr.base_priority` is invalid
+        /// - [Error::InvalidParam]: `attr.period` is invalid
+        /// - [Error::InvalidConfig]: `attr.period` is positive and `attr.period` is not dividable by the partition period
+        /// - [Error::InvalidParam]: `attr.time_capacity` is invalid
+        /// - [Error::InvalidParam]: `attr.period` is positive and `attr.period` is less than `attr.time_capacity`
         pub fn create_process(&mut self, attr: ProcessAttribute) -> Result<Process<P>, Error> {
             let id = P::create_process(&attr.into())?;
             Ok(Process {
@@ -333,6 +496,8 @@ pub mod abstraction {
     }
 
     impl<P: ApexProcessP1> StartContext<P> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: `processor_core_id` is not assigned to this partition
         pub fn initialize_process_core_affinity(
             &self,
             process: &Process<P>,
@@ -342,4 +507,301 @@ pub mod abstraction {
             Ok(())
         }
     }
+
+    /// Limits of an [ExecutionMonitor], whose breaches are reported via
+    /// [report_application_message](ApexErrorP4Ext::report_application_message)
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct MonitorThresholds {
+        /// Maximum execution time of a single activation
+        pub execution_time: Option<Duration>,
+        /// Minimum time left until the deadline at the end of an activation.
+        /// Missed deadlines are reported as soon as this is set.
+        pub slack: Option<Duration>,
+        /// Maximum deviation of the time between two activations from the period
+        pub jitter: Option<Duration>,
+    }
+
+    /// Execution time statistics collected by an [ExecutionMonitor]
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct ExecutionStats {
+        /// Number of completed activations
+        pub activations: u64,
+        pub worst_execution_time: Duration,
+        pub total_execution_time: Duration,
+        /// Largest deviation of the time between two activations from the period
+        pub max_jitter: Duration,
+        /// Smallest time left until the deadline at the end of an activation.
+        /// [None] if the process never had a deadline.
+        pub min_slack: Option<Duration>,
+        /// Number of activations which ended after their deadline
+        pub deadline_misses: u64,
+    }
+
+    impl ExecutionStats {
+        /// [None] before the first completed activation
+        pub fn average_execution_time(&self) -> Option<Duration> {
+            let average = self
+                .total_execution_time
+                .as_nanos()
+                .checked_div(u128::from(self.activations))?;
+            Some(Duration::from_nanos(
+                u64::try_from(average).unwrap_or(u64::MAX),
+            ))
+        }
+    }
+
+    /// Opt-in execution time and deadline monitor of the calling process
+    ///
+    /// Each activation is enclosed in [ExecutionMonitor::start] and [ExecutionMonitor::end],
+    /// which measure it using [ApexTimeP4Ext::now].
+    /// Only aggregated [ExecutionStats] are kept, hence the memory footprint is constant.
+    #[derive(Debug)]
+    pub struct ExecutionMonitor<P: ApexProcessP1 + ApexTimeP4Ext + ApexErrorP4Ext> {
+        process: Process<P>,
+        name: Name,
+        period: Option<Duration>,
+        thresholds: MonitorThresholds,
+        stats: ExecutionStats,
+        activation: Option<Instant>,
+        last_activation: Option<Instant>,
+    }
+
+    impl<P: ApexProcessP1 + ApexTimeP4Ext + ApexErrorP4Ext> ExecutionMonitor<P> {
+        /// Monitor of the calling process
+        ///
+        /// # Errors
+        /// - [Error::InvalidMode]: not called from within a process
+        /// - [Error::InvalidParam]: the calling process does not exist anymore
+        pub fn new(thresholds: MonitorThresholds) -> Result<Self, Error> {
+            let process = Process::get_self()?;
+            let attributes = process.try_status()?.attributes;
+            Ok(Self {
+                process,
+                name: attributes.name,
+                period: attributes.period.into(),
+                thresholds,
+                stats: ExecutionStats::default(),
+                activation: None,
+                last_activation: None,
+            })
+        }
+
+        pub fn thresholds(&self) -> &MonitorThresholds {
+            &self.thresholds
+        }
+
+        pub fn stats(&self) -> &ExecutionStats {
+            &self.stats
+        }
+
+        /// Discards all collected statistics
+        pub fn reset(&mut self) {
+            self.stats = ExecutionStats::default();
+            self.activation = None;
+            self.last_activation = None;
+        }
+
+        /// Marks the start of an activation
+        ///
+        /// For periodic processes, the time since the previous start is compared to the period.
+        /// If the current time is not available, this is reported and the activation is not measured.
+        pub fn start(&mut self) {
+            let Ok(now) = P::now() else {
+                self.activation = None;
+                return self.report(format_args!("current time not available"));
+            };
+            if let (Some(last), Some(period)) = (self.last_activation, self.period) {
+                let jitter = now.duration_since(last).abs_diff(period);
+                self.stats.max_jitter = self.stats.max_jitter.max(jitter);
+                if let Some(limit) = self.thresholds.jitter.filter(|l| jitter > *l) {
+                    self.report(format_args!("jitter {jitter:?} exceeds {limit:?}"));
+                }
+            }
+            self.last_activation = Some(now);
+            self.activation = Some(now);
+        }
+
+        /// Marks the end of the activation started by [ExecutionMonitor::start]
+        ///
+        /// Does nothing if there is no started activation.
+        pub fn end(&mut self) {
+            let Some(start) = self.activation.take() else {
+                return;
+            };
+            let Ok(end) = P::now() else {
+                return self.report(format_args!("current time not available"));
+            };
+            let execution_time = end.duration_since(start);
+            self.stats.activations += 1;
+            self.stats.total_execution_time += execution_time;
+            self.stats.worst_execution_time = self.stats.worst_execution_time.max(execution_time);
+            if let Some(limit) = self
+                .thresholds
+                .execution_time
+                .filter(|l| execution_time > *l)
+            {
+                self.report(format_args!(
+                    "execution time {execution_time:?} exceeds {limit:?}"
+                ));
+            }
+
+            // Processes without a deadline have an infinite deadline time
+            let Some(deadline) = self
+                .process
+                .try_status()
+                .and_then(|status| Instant::try_from(status.deadline_time))
+                .ok()
+            else {
+                return;
+            };
+            let slack = deadline.checked_duration_since(end);
+            let min_slack = slack.unwrap_or(Duration::ZERO);
+            self.stats.min_slack =
+                Some(self.stats.min_slack.map_or(min_slack, |s| s.min(min_slack)));
+            if slack.is_none() {
+                self.stats.deadline_misses += 1;
+            }
+            let Some(limit) = self.thresholds.slack else {
+                return;
+            };
+            match slack {
+                None => {
+                    let late = end.duration_since(deadline);
+                    self.report(format_args!("deadline missed by {late:?}"));
+                }
+                Some(slack) if slack < limit => {
+                    self.report(format_args!("slack {slack:?} below {limit:?}"))
+                }
+                Some(_) => {}
+            }
+        }
+
+        /// Monitors `activation` as a single activation
+        pub fn measure<R>(&mut self, activation: impl FnOnce() -> R) -> R {
+            self.start();
+            let result = activation();
+            self.end();
+            result
+        }
+
+        fn report(&self, breach: core::fmt::Arguments) {
+            let name = self.name.to_str().unwrap_or("?");
+            // Reporting is best effort, the message can not be too large
+            let _ = with_message(
+                format_args!("{name}: {breach}"),
+                <P as ApexErrorP4Ext>::report_application_message,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::abstraction::*;
+    use crate::apex::mock::MockHypervisor;
+    use crate::apex::time::basic::INFINITE_TIME_VALUE;
+
+    const MS: i64 = 1_000_000;
+
+    fn monitor(thresholds: MonitorThresholds) -> ExecutionMonitor<MockHypervisor> {
+        MockHypervisor::set_time(1000 * MS);
+        MockHypervisor::set_deadline(INFINITE_TIME_VALUE);
+        MockHypervisor::set_period(INFINITE_TIME_VALUE);
+        MockHypervisor::take_messages();
+        ExecutionMonitor::new(thresholds).unwrap()
+    }
+
+    #[test]
+    fn execution_time_overrun_is_reported() {
+        let mut monitor = monitor(MonitorThresholds {
+            execution_time: Some(Duration::from_millis(5)),
+            ..Default::default()
+        });
+
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(3)));
+        assert!(MockHypervisor::take_messages().is_empty());
+
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(7)));
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["mock: execution time 7ms exceeds 5ms"]
+        );
+
+        let stats = monitor.stats();
+        assert_eq!(stats.activations, 2);
+        assert_eq!(stats.worst_execution_time, Duration::from_millis(7));
+        assert_eq!(
+            stats.average_execution_time(),
+            Some(Duration::from_millis(5))
+        );
+        assert_eq!(stats.min_slack, None);
+    }
+
+    #[test]
+    fn low_slack_is_reported() {
+        let mut monitor = monitor(MonitorThresholds {
+            slack: Some(Duration::from_millis(2)),
+            ..Default::default()
+        });
+        MockHypervisor::set_deadline(1010 * MS);
+
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(5)));
+        assert!(MockHypervisor::take_messages().is_empty());
+
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(4)));
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["mock: slack 1ms below 2ms"]
+        );
+        assert_eq!(monitor.stats().min_slack, Some(Duration::from_millis(1)));
+        assert_eq!(monitor.stats().deadline_misses, 0);
+    }
+
+    #[test]
+    fn deadline_miss_is_counted_and_reported() {
+        let mut monitor = monitor(MonitorThresholds::default());
+        MockHypervisor::set_deadline(1002 * MS);
+
+        // Without a slack threshold, misses are only counted
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(3)));
+        assert!(MockHypervisor::take_messages().is_empty());
+        assert_eq!(monitor.stats().deadline_misses, 1);
+        assert_eq!(monitor.stats().min_slack, Some(Duration::ZERO));
+
+        let mut monitor = self::monitor(MonitorThresholds {
+            slack: Some(Duration::ZERO),
+            ..Default::default()
+        });
+        MockHypervisor::set_deadline(1002 * MS);
+        monitor.measure(|| MockHypervisor::advance(Duration::from_millis(3)));
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["mock: deadline missed by 1ms"]
+        );
+        assert_eq!(monitor.stats().deadline_misses, 1);
+    }
+
+    #[test]
+    fn jitter_is_reported() {
+        let thresholds = MonitorThresholds {
+            jitter: Some(Duration::from_millis(1)),
+            ..Default::default()
+        };
+        monitor(thresholds);
+        // The period is read when the monitor is created
+        MockHypervisor::set_period(10 * MS);
+        let mut monitor = ExecutionMonitor::<MockHypervisor>::new(thresholds).unwrap();
+
+        for time in [10, 12, 10] {
+            monitor.measure(|| MockHypervisor::advance(Duration::from_millis(time)));
+        }
+        assert_eq!(
+            MockHypervisor::take_messages(),
+            ["mock: jitter 2ms exceeds 1ms"]
+        );
+        assert_eq!(monitor.stats().max_jitter, Duration::from_millis(2));
+    }
 }
@@ -28,6 +28,14 @@ pub mod basic {
     }
 
     pub trait ApexQueuingPortP4 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: not enough memory is available
+        /// - [ErrorReturnCode::InvalidConfig]: no queuing port with `queuing_port_name` is configured for this partition
+        /// - [ErrorReturnCode::InvalidConfig]: `max_message_size`, `max_nb_message` or `port_direction` does not match the configuration
+        /// - [ErrorReturnCode::NoAction]: queuing port with `queuing_port_name` was already created
+        /// - [ErrorReturnCode::InvalidParam]: `max_message_size` is invalid
+        /// - [ErrorReturnCode::InvalidParam]: `max_nb_message` is invalid
+        /// - [ErrorReturnCode::InvalidMode]: our current operating mode is [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
         // Only during Warm/Cold-Start
         fn create_queuing_port(
             queuing_port_name: QueuingPortName,
@@ -37,6 +45,16 @@ pub mod basic {
             queuing_discipline: QueuingDiscipline,
         ) -> Result<QueuingPortId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: queuing port with `queuing_port_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `time_out` is invalid
+        /// - [ErrorReturnCode::InvalidParam]: `message` length is zero
+        /// - [ErrorReturnCode::InvalidConfig]: `message` is longer than the `max_message_size` of this port
+        /// - [ErrorReturnCode::InvalidMode]: queuing port with `queuing_port_id` is not a source port
+        /// - [ErrorReturnCode::InvalidMode]: current process holds a mutex
+        /// - [ErrorReturnCode::InvalidMode]: current process is error handler AND `time_out` is not instant.
+        /// - [ErrorReturnCode::NotAvailable]: `time_out` is instant AND there is no place in the queue
+        /// - [ErrorReturnCode::TimedOut]: `time_out` elapsed
         fn send_queuing_message(
             queuing_port_id: QueuingPortId,
             message: &[ApexByte],
@@ -46,20 +64,36 @@ pub mod basic {
         /// # Safety
         ///
         /// This function is safe, as long as the buffer can hold whatever is received
+        ///
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: queuing port with `queuing_port_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `time_out` is invalid
+        /// - [ErrorReturnCode::InvalidMode]: queuing port with `queuing_port_id` is not a destination port
+        /// - [ErrorReturnCode::InvalidMode]: current process holds a mutex
+        /// - [ErrorReturnCode::InvalidMode]: current process is error handler AND `time_out` is not instant.
+        /// - [ErrorReturnCode::NotAvailable]: `time_out` is instant AND there is no message in the queue
+        /// - [ErrorReturnCode::TimedOut]: `time_out` elapsed
         unsafe fn receive_queuing_message(
             queuing_port_id: QueuingPortId,
             time_out: ApexSystemTime,
             message: &mut [ApexByte],
         ) -> Result<(MessageSize, QueueOverflow), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: queuing port with `queuing_port_id` does not exist
         fn get_queuing_port_status(
             queuing_port_id: QueuingPortId,
         ) -> Result<QueuingPortStatus, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: queuing port with `queuing_port_id` does not exist
+        /// - [ErrorReturnCode::InvalidMode]: queuing port with `queuing_port_id` is not a destination port
         fn clear_queuing_port(queuing_port_id: QueuingPortId) -> Result<(), ErrorReturnCode>;
     }
 
     pub trait ApexQueuingPortP1: ApexQueuingPortP4 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: queuing port with `queuing_port_name` does not exist
         fn get_queuing_port_id(
             queuing_port_name: QueuingPortName,
         ) -> Result<QueuingPortId, ErrorReturnCode>;
@@ -173,6 +207,206 @@ pub mod abstraction {
         }
     }
 
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, Q: ApexQueuingPortP4Ext>
+        ConstQueuingPortSender<MSG_SIZE, NB_MSGS, Q>
+    {
+        /// Queuing port send from an array, whose length is checked at compile time
+        ///
+        /// Using an empty array or one longer than `MSG_SIZE` fails to build.
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no place in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn send_array<const N: usize>(
+            &self,
+            msg: &[ApexByte; N],
+            timeout: SystemTime,
+        ) -> Result<(), Error> {
+            const {
+                assert!(N > 0, "message must not be empty");
+                assert!(N <= MSG_SIZE as usize, "message is longer than MSG_SIZE");
+            }
+            Q::queueing_port_send_unchecked(self.0.id, msg, timeout)
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange, Q: ApexQueuingPortP4Ext>
+        ConstQueuingPortReceiver<MSG_SIZE, NB_MSGS, Q>
+    {
+        /// Queuing port receive into an owned [Message] of `MSG_SIZE` bytes
+        ///
+        /// `N` must be `MSG_SIZE`, anything else fails to build.
+        /// It can be inferred from the type of the returned [Message],
+        /// but not from `MSG_SIZE`, as that is a [MessageSize].
+        ///
+        /// ```
+        /// # use a653rs::prelude::*;
+        /// fn receive<Q: ApexQueuingPortP4Ext>(
+        ///     port: &ConstQueuingPortReceiver<64, 8, Q>,
+        /// ) -> Result<Message<64>, Error> {
+        ///     let (msg, _) = port.receive_owned(SystemTime::Infinite)?;
+        ///     Ok(msg)
+        /// }
+        /// ```
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no message in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn receive_owned<const N: usize>(
+            &self,
+            timeout: SystemTime,
+        ) -> Result<(Message<N>, QueueOverflow), Error> {
+            const { assert!(N == MSG_SIZE as usize, "N must be MSG_SIZE") }
+            let mut data = [0; N];
+            // The array can hold exactly `MSG_SIZE` bytes
+            let (msg, overflow) =
+                unsafe { Q::queueing_port_receive_unchecked(self.0.id, timeout, &mut data) }?;
+            let len = msg.len();
+            Ok((Message::new(data, len), overflow))
+        }
+    }
+
+    /// Queuing port sender sending messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedQueuingPortSender<T: Pod, Q: ApexQueuingPortP4Ext>(
+        QueuingPortSender<Q>,
+        PhantomData<T>,
+    );
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> Clone for TypedQueuingPortSender<T, Q> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> Deref for TypedQueuingPortSender<T, Q> {
+        type Target = QueuingPortSender<Q>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> TryFrom<QueuingPortSender<Q>>
+        for TypedQueuingPortSender<T, Q>
+    {
+        type Error = Error;
+
+        fn try_from(port: QueuingPortSender<Q>) -> Result<Self, Self::Error> {
+            if port.msg_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedQueuingPortSender(port, PhantomData))
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> TypedQueuingPortSender<T, Q> {
+        /// # Errors
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no place in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn send(&self, msg: &T, timeout: SystemTime) -> Result<(), Error> {
+            self.0.send(msg.as_bytes(), timeout)
+        }
+
+        /// [TypedQueuingPortSender::send] with this port as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedQueuingPortSender::send]
+        pub fn send_ctx(&self, msg: &T, timeout: SystemTime) -> Result<(), ContextError> {
+            let id = self.id();
+            let msg = msg
+                .as_bytes()
+                .validate_write(T::SIZE)
+                .context(Operation::QueuingSend, id)?;
+            Q::send_queuing_message(id, msg, timeout.into()).context(Operation::QueuingSend, id)
+        }
+    }
+
+    /// Queuing port receiver receiving messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedQueuingPortReceiver<T: Pod, Q: ApexQueuingPortP4Ext>(
+        QueuingPortReceiver<Q>,
+        PhantomData<T>,
+    );
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> Clone for TypedQueuingPortReceiver<T, Q> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> Deref for TypedQueuingPortReceiver<T, Q> {
+        type Target = QueuingPortReceiver<Q>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> TryFrom<QueuingPortReceiver<Q>>
+        for TypedQueuingPortReceiver<T, Q>
+    {
+        type Error = Error;
+
+        fn try_from(port: QueuingPortReceiver<Q>) -> Result<Self, Self::Error> {
+            if port.msg_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedQueuingPortReceiver(port, PhantomData))
+        }
+    }
+
+    impl<T: Pod, Q: ApexQueuingPortP4Ext> TypedQueuingPortReceiver<T, Q> {
+        /// # Errors
+        /// - [Error::ReadError]: the received message is not of the size of `T`
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no message in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
+        pub fn receive(&self, timeout: SystemTime) -> Result<(T, QueueOverflow), Error> {
+            let mut msg = T::zeroed();
+            let (data, overflow) = self.0.receive(msg.as_bytes_mut(), timeout)?;
+            if data.len() != core::mem::size_of::<T>() {
+                return Err(Error::ReadError);
+            }
+            Ok((msg, overflow))
+        }
+
+        /// [TypedQueuingPortReceiver::receive] with this port as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedQueuingPortReceiver::receive]
+        pub fn receive_ctx(&self, timeout: SystemTime) -> Result<(T, QueueOverflow), ContextError> {
+            let id = self.id();
+            let mut msg = T::zeroed();
+            // The message is exactly as large as the messages of this port
+            let (len, overflow) =
+                unsafe { Q::receive_queuing_message(id, timeout.into(), msg.as_bytes_mut()) }
+                    .context(Operation::QueuingReceive, id)?;
+            if len != T::SIZE {
+                return Err(ContextError::new(
+                    Operation::QueuingReceive,
+                    id,
+                    Error::ReadError,
+                ));
+            }
+            Ok((msg, overflow))
+        }
+    }
+
     #[derive(Debug)]
     pub struct QueuingPortSender<Q: ApexQueuingPortP4Ext> {
         _b: PhantomData<AtomicPtr<Q>>,
@@ -228,6 +462,15 @@ pub mod abstraction {
     }
 
     pub trait ApexQueuingPortP4Ext: ApexQueuingPortP4 + Sized {
+        /// # Errors
+        /// - [Error::InvalidParam]: queuing port with `id` does not exist
+        /// - [Error::InvalidParam]: `buffer` length is zero
+        /// - [Error::InvalidConfig]: `buffer` is longer than the `max_message_size` of this port
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no place in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
         fn queueing_port_send_unchecked(
             id: QueuingPortId,
             buffer: &[ApexByte],
@@ -237,6 +480,14 @@ pub mod abstraction {
         /// # Safety
         ///
         /// This function is safe, as long as the buffer can hold whatever is received
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: queuing port with `id` does not exist
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no message in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
         unsafe fn queueing_port_receive_unchecked(
             id: QueuingPortId,
             timeout: SystemTime,
@@ -245,16 +496,18 @@ pub mod abstraction {
     }
 
     pub trait ApexQueuingPortP1Ext: ApexQueuingPortP1 + Sized {
-        /// Returns Err(Error::InvalidConfig) if queuing port with name does not exists or
-        /// if the message size of the found queuing port is different than MSG_SIZE or
-        /// if number messages of found queuing port is different than NB_MSGS
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different `MSG_SIZE` or `NB_MSGS`
         fn get_const_queuing_port_sender<const MSG_SIZE: MessageSize, const NB_MSGS: MessageRange>(
             name: Name,
         ) -> Result<ConstQueuingPortSender<MSG_SIZE, NB_MSGS, Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if queuing port with name does not exists or
-        /// if the message size of the found queuing port is different than MSG_SIZE or
-        /// if number messages of found queuing port is different than NB_MSGS
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different `MSG_SIZE` or `NB_MSGS`
         fn get_const_queuing_port_receiver<
             const MSG_SIZE: MessageSize,
             const NB_MSGS: MessageRange,
@@ -262,10 +515,14 @@ pub mod abstraction {
             name: Name,
         ) -> Result<ConstQueuingPortReceiver<MSG_SIZE, NB_MSGS, Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if queuing port with name does not exists
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
         fn get_queuing_port_sender(name: Name) -> Result<QueuingPortSender<Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if queuing port with name does not exists
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
         fn get_queuing_port_receiver(name: Name) -> Result<QueuingPortReceiver<Self>, Error>;
     }
 
@@ -320,13 +577,14 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.6.2.2.5 this can only fail if the queuing_port_id
             //  does not exist in the current partition.
             // But since we retrieve the queuing_port_id directly from the hypervisor
-            //  there is no possible way for it not existing
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
             let QueuingPortStatus {
                 max_nb_message: nb_msgs,
                 max_message_size: msg_size,
                 port_direction,
                 ..
-            } = Q::get_queuing_port_status(id).unwrap();
+            } = Q::get_queuing_port_status(id)?;
 
             if port_direction != PortDirection::Source {
                 return Err(Error::InvalidConfig);
@@ -346,13 +604,14 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.6.2.2.5 this can only fail if the queuing_port_id
             //  does not exist in the current partition.
             // But since we retrieve the queuing_port_id directly from the hypervisor
-            //  there is no possible way for it not existing
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
             let QueuingPortStatus {
                 max_nb_message: nb_msgs,
                 max_message_size: msg_size,
                 port_direction,
                 ..
-            } = Q::get_queuing_port_status(id).unwrap();
+            } = Q::get_queuing_port_status(id)?;
 
             if port_direction != PortDirection::Destination {
                 return Err(Error::InvalidConfig);
@@ -368,6 +627,16 @@ pub mod abstraction {
     }
 
     impl<Q: ApexQueuingPortP4Ext> QueuingPortSender<Q> {
+        /// Checked queuing port send from specified byte buffer
+        ///
+        /// # Errors
+        /// - [Error::InvalidConfig]: the `buffer` is longer than the `max_message_size` specified for this port
+        /// - [Error::InvalidParam]: `buffer` length is zero
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no place in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
         pub fn send(&self, buffer: &[ApexByte], timeout: SystemTime) -> Result<(), Error> {
             buffer.validate_write(self.msg_size)?;
             Q::queueing_port_send_unchecked(self.id, buffer, timeout)
@@ -385,22 +654,43 @@ pub mod abstraction {
             self.nb_msgs
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this queuing port does not exist anymore
+        pub fn try_status(&self) -> Result<QueuingPortStatus, Error> {
+            Ok(Q::get_queuing_port_status(self.id)?)
+        }
+
+        /// # Panics
+        /// if this queuing port does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> QueuingPortStatus {
             // According to ARINC653P1-5 3.6.2.2.5 this can only fail if the queuing_port_id
             //  does not exist in the current partition.
             // But since we retrieve the queuing_port_id directly from the hypervisor
             //  there is no possible way for it not existing
-            Q::get_queuing_port_status(self.id).unwrap()
+            self.try_status().unwrap()
         }
     }
 
     impl<Q: ApexQueuingPortP1Ext> QueuingPortSender<Q> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
         pub fn from_name(name: Name) -> Result<QueuingPortSender<Q>, Error> {
             Q::get_queuing_port_sender(name)
         }
     }
 
     impl<Q: ApexQueuingPortP4Ext> QueuingPortReceiver<Q> {
+        /// Checked queuing port receive into specified byte buffer
+        ///
+        /// # Errors
+        /// - [Error::ReadError]: prodived `buffer` is too small for this port's `max_message_size`
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex
+        /// - [Error::InvalidMode]: current process is error handler AND `timeout` is not instant.
+        /// - [Error::NotAvailable]: `timeout` is instant AND there is no message in the queue
+        /// - [Error::TimedOut]: `timeout` elapsed
         pub fn receive<'a>(
             &self,
             buffer: &'a mut [ApexByte],
@@ -410,13 +700,41 @@ pub mod abstraction {
             unsafe { Q::queueing_port_receive_unchecked(self.id, timeout, buffer) }
         }
 
+        /// Checked queuing port receive into an owned [Message]
+        ///
+        /// `N` must be named and at least the `max_message_size` of this port.
+        /// Prefer [ConstQueuingPortReceiver::receive_owned] if the size is known at compile time.
+        ///
+        /// # Errors
+        /// - [Error::ReadError]: `N` is smaller than this port's `max_message_size`
+        /// - errors of [QueuingPortReceiver::receive]
+        pub fn receive_owned<const N: usize>(
+            &self,
+            timeout: SystemTime,
+        ) -> Result<(Message<N>, QueueOverflow), Error> {
+            let mut data = [0; N];
+            let (msg, overflow) = self.receive(&mut data, timeout)?;
+            let len = msg.len();
+            Ok((Message::new(data, len), overflow))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidParam]: this queuing port does not exist anymore
+        pub fn try_clear(&self) -> Result<(), Error> {
+            Q::clear_queuing_port(self.id)?;
+            Ok(())
+        }
+
+        /// # Panics
+        /// if this queuing port does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn clear(&self) {
             // According to ARINC653P1-5 3.6.2.2.6 this can only fail if the queuing_port_id does not exist
             //  in the current partition or if this is not a destination port.
             // But since we retrieve the queuing_port_id directly from the hypervisor
             //  and we verify that this is a destination port,
             //  there is no possible way for it not existing
-            Q::clear_queuing_port(self.id).unwrap();
+            self.try_clear().unwrap();
         }
 
         pub const fn id(&self) -> QueuingPortId {
@@ -431,22 +749,72 @@ pub mod abstraction {
             self.nb_msgs
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this queuing port does not exist anymore
+        pub fn try_status(&self) -> Result<QueuingPortStatus, Error> {
+            Ok(Q::get_queuing_port_status(self.id)?)
+        }
+
+        /// # Panics
+        /// if this queuing port does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> QueuingPortStatus {
             // According to ARINC653P1-5 3.6.2.2.5 this can only fail if the queuing_port_id
             //  does not exist in the current partition.
             // But since we retrieve the queuing_port_id directly from the hypervisor
             //  there is no possible way for it not existing
-            Q::get_queuing_port_status(self.id).unwrap()
+            self.try_status().unwrap()
         }
     }
 
     impl<Q: ApexQueuingPortP1Ext> QueuingPortReceiver<Q> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: queuing port with `name` does not exist
+        /// - [Error::InvalidConfig]: queuing port with `name` has a different direction
         pub fn from_name(name: Name) -> Result<QueuingPortReceiver<Q>, Error> {
             Q::get_queuing_port_receiver(name)
         }
     }
 
     impl<Q: ApexQueuingPortP4Ext> StartContext<Q> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the size of `T`, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the size of `T` or range is invalid
+        pub fn create_typed_queuing_port_sender<T: Pod>(
+            &mut self,
+            name: Name,
+            nb_msgs: MessageRange,
+            qd: QueuingDiscipline,
+        ) -> Result<TypedQueuingPortSender<T, Q>, Error> {
+            let port = self.create_queuing_port_sender(name, T::SIZE, nb_msgs, qd)?;
+            Ok(TypedQueuingPortSender(port, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the size of `T`, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the size of `T` or range is invalid
+        pub fn create_typed_queuing_port_receiver<T: Pod>(
+            &mut self,
+            name: Name,
+            nb_msgs: MessageRange,
+            qd: QueuingDiscipline,
+        ) -> Result<TypedQueuingPortReceiver<T, Q>, Error> {
+            let port = self.create_queuing_port_receiver(name, T::SIZE, nb_msgs, qd)?;
+            Ok(TypedQueuingPortReceiver(port, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the port size or range is invalid
         pub fn create_const_queuing_port_sender<
             const MSG_SIZE: MessageSize,
             const NB_MSGS: MessageRange,
@@ -459,6 +827,12 @@ pub mod abstraction {
             Ok(ConstQueuingPortSender(port))
         }
 
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the port size or range is invalid
         pub fn create_const_queuing_port_receiver<
             const MSG_SIZE: MessageSize,
             const NB_MSGS: MessageRange,
@@ -471,6 +845,12 @@ pub mod abstraction {
             Ok(ConstQueuingPortReceiver(port))
         }
 
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the port size or range is invalid
         pub fn create_queuing_port_sender(
             &mut self,
             name: Name,
@@ -489,6 +869,12 @@ pub mod abstraction {
             })
         }
 
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no queuing port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size, range or direction does not match the configuration
+        /// - [Error::NoAction]: queuing port with `name` was already created
+        /// - [Error::InvalidParam]: the port size or range is invalid
         pub fn create_queuing_port_receiver(
             &mut self,
             name: Name,
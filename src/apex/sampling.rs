@@ -31,6 +31,14 @@ pub mod basic {
     }
 
     pub trait ApexSamplingPortP4 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: not enough memory is available
+        /// - [ErrorReturnCode::InvalidConfig]: no sampling port with `sampling_port_name` is configured for this partition
+        /// - [ErrorReturnCode::InvalidConfig]: `max_message_size` or `port_direction` does not match the configuration
+        /// - [ErrorReturnCode::InvalidConfig]: `refresh_period` is invalid
+        /// - [ErrorReturnCode::NoAction]: sampling port with `sampling_port_name` was already created
+        /// - [ErrorReturnCode::InvalidParam]: `max_message_size` is invalid
+        /// - [ErrorReturnCode::InvalidMode]: our current operating mode is [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
         // Only during Warm/Cold-Start
         fn create_sampling_port(
             sampling_port_name: SamplingPortName,
@@ -39,6 +47,11 @@ pub mod basic {
             refresh_period: ApexSystemTime,
         ) -> Result<SamplingPortId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: sampling port with `sampling_port_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `message` length is zero
+        /// - [ErrorReturnCode::InvalidConfig]: `message` is longer than the `max_message_size` of this port
+        /// - [ErrorReturnCode::InvalidMode]: sampling port with `sampling_port_id` is not a source port
         fn write_sampling_message(
             sampling_port_id: SamplingPortId,
             message: &[ApexByte],
@@ -47,6 +60,11 @@ pub mod basic {
         /// # Safety
         ///
         /// This function is safe, as long as the buffer can hold whatever is received
+        ///
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: sampling port with `sampling_port_id` does not exist
+        /// - [ErrorReturnCode::InvalidMode]: sampling port with `sampling_port_id` is not a destination port
+        /// - [ErrorReturnCode::NoAction]: there is no message in the port
         unsafe fn read_sampling_message(
             sampling_port_id: SamplingPortId,
             message: &mut [ApexByte],
@@ -54,10 +72,14 @@ pub mod basic {
     }
 
     pub trait ApexSamplingPortP1: ApexSamplingPortP4 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: sampling port with `sampling_port_name` does not exist
         fn get_sampling_port_id(
             sampling_port_name: SamplingPortName,
         ) -> Result<SamplingPortId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: sampling port with `sampling_port_id` does not exist
         fn get_sampling_port_status(
             sampling_port_id: SamplingPortId,
         ) -> Result<ApexSamplingPortStatus, ErrorReturnCode>;
@@ -188,6 +210,179 @@ pub mod abstraction {
         }
     }
 
+    impl<const MSG_SIZE: MessageSize, S: ApexSamplingPortP4Ext> ConstSamplingPortSource<MSG_SIZE, S> {
+        /// Sampling port send from an array, whose length is checked at compile time
+        ///
+        /// Using an empty array or one longer than `MSG_SIZE` fails to build.
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: this sampling port does not exist anymore
+        pub fn send_array<const N: usize>(&self, msg: &[ApexByte; N]) -> Result<(), Error> {
+            const {
+                assert!(N > 0, "message must not be empty");
+                assert!(N <= MSG_SIZE as usize, "message is longer than MSG_SIZE");
+            }
+            S::sampling_port_send_unchecked(self.0.id, msg)
+        }
+    }
+
+    impl<const MSG_SIZE: MessageSize, S: ApexSamplingPortP4Ext>
+        ConstSamplingPortDestination<MSG_SIZE, S>
+    {
+        /// Sampling port receive into an owned [Message] of `MSG_SIZE` bytes
+        ///
+        /// `N` must be `MSG_SIZE`, anything else fails to build.
+        /// It can be inferred from the type of the returned [Message],
+        /// but not from `MSG_SIZE`, as that is a [MessageSize].
+        ///
+        /// ```
+        /// # use a653rs::prelude::*;
+        /// fn receive<S: ApexSamplingPortP4Ext>(
+        ///     port: &ConstSamplingPortDestination<64, S>,
+        /// ) -> Result<Message<64>, Error> {
+        ///     let (_, msg) = port.receive_owned()?;
+        ///     Ok(msg)
+        /// }
+        /// ```
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: there is no message in the port
+        pub fn receive_owned<const N: usize>(&self) -> Result<(Validity, Message<N>), Error> {
+            const { assert!(N == MSG_SIZE as usize, "N must be MSG_SIZE") }
+            let mut data = [0; N];
+            // The array can hold exactly `MSG_SIZE` bytes
+            let (validity, msg) =
+                unsafe { S::sampling_port_receive_unchecked(self.0.id, &mut data) }?;
+            let len = msg.len();
+            Ok((validity, Message::new(data, len)))
+        }
+    }
+
+    /// Sampling port source sending messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedSamplingPortSource<T: Pod, S: ApexSamplingPortP4Ext>(
+        SamplingPortSource<S>,
+        PhantomData<T>,
+    );
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> Clone for TypedSamplingPortSource<T, S> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> Deref for TypedSamplingPortSource<T, S> {
+        type Target = SamplingPortSource<S>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> TryFrom<SamplingPortSource<S>>
+        for TypedSamplingPortSource<T, S>
+    {
+        type Error = Error;
+
+        fn try_from(port: SamplingPortSource<S>) -> Result<Self, Self::Error> {
+            if port.msg_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedSamplingPortSource(port, PhantomData))
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> TypedSamplingPortSource<T, S> {
+        /// # Errors
+        /// - [Error::InvalidParam]: `T` is zero-sized
+        pub fn send(&self, msg: &T) -> Result<(), Error> {
+            self.0.send(msg.as_bytes())
+        }
+
+        /// [TypedSamplingPortSource::send] with this port as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedSamplingPortSource::send]
+        pub fn send_ctx(&self, msg: &T) -> Result<(), ContextError> {
+            let id = self.id();
+            let msg = msg
+                .as_bytes()
+                .validate_write(T::SIZE)
+                .context(Operation::SamplingWrite, id)?;
+            S::write_sampling_message(id, msg).context(Operation::SamplingWrite, id)
+        }
+    }
+
+    /// Sampling port destination receiving messages of type `T`
+    #[derive(Debug)]
+    pub struct TypedSamplingPortDestination<T: Pod, S: ApexSamplingPortP4Ext>(
+        SamplingPortDestination<S>,
+        PhantomData<T>,
+    );
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> Clone for TypedSamplingPortDestination<T, S> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1)
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> Deref for TypedSamplingPortDestination<T, S> {
+        type Target = SamplingPortDestination<S>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> TryFrom<SamplingPortDestination<S>>
+        for TypedSamplingPortDestination<T, S>
+    {
+        type Error = Error;
+
+        fn try_from(port: SamplingPortDestination<S>) -> Result<Self, Self::Error> {
+            if port.msg_size != T::SIZE {
+                return Err(Error::InvalidConfig);
+            }
+
+            Ok(TypedSamplingPortDestination(port, PhantomData))
+        }
+    }
+
+    impl<T: Pod, S: ApexSamplingPortP4Ext> TypedSamplingPortDestination<T, S> {
+        /// # Errors
+        /// - [Error::NoAction]: there is no message in the port
+        /// - [Error::ReadError]: the received message is not of the size of `T`
+        pub fn receive(&self) -> Result<(Validity, T), Error> {
+            let mut msg = T::zeroed();
+            let (validity, data) = self.0.receive(msg.as_bytes_mut())?;
+            if data.len() != core::mem::size_of::<T>() {
+                return Err(Error::ReadError);
+            }
+            Ok((validity, msg))
+        }
+
+        /// [TypedSamplingPortDestination::receive] with this port as context of the error
+        ///
+        /// # Errors
+        /// - errors of [TypedSamplingPortDestination::receive]
+        pub fn receive_ctx(&self) -> Result<(Validity, T), ContextError> {
+            let id = self.id();
+            let mut msg = T::zeroed();
+            // The message is exactly as large as the messages of this port
+            let (validity, len) = unsafe { S::read_sampling_message(id, msg.as_bytes_mut()) }
+                .context(Operation::SamplingRead, id)?;
+            if len != T::SIZE {
+                return Err(ContextError::new(
+                    Operation::SamplingRead,
+                    id,
+                    Error::ReadError,
+                ));
+            }
+            Ok((validity, msg))
+        }
+    }
+
     #[derive(Debug)]
     pub struct SamplingPortSource<S: ApexSamplingPortP4Ext> {
         _b: PhantomData<AtomicPtr<S>>,
@@ -241,6 +436,10 @@ pub mod abstraction {
     }
 
     pub trait ApexSamplingPortP4Ext: ApexSamplingPortP4 + Sized {
+        /// # Errors
+        /// - [Error::InvalidParam]: sampling port with `id` does not exist
+        /// - [Error::InvalidParam]: `buffer` length is zero
+        /// - [Error::InvalidConfig]: `buffer` is longer than the `max_message_size` of this port
         fn sampling_port_send_unchecked(
             id: SamplingPortId,
             buffer: &[ApexByte],
@@ -249,6 +448,10 @@ pub mod abstraction {
         /// # Safety
         ///
         /// This function is safe, as long as the buffer can hold whatever is received
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: sampling port with `id` does not exist
+        /// - [Error::NoAction]: there is no message in the port
         unsafe fn sampling_port_receive_unchecked(
             id: SamplingPortId,
             buffer: &mut [ApexByte],
@@ -256,22 +459,30 @@ pub mod abstraction {
     }
 
     pub trait ApexSamplingPortP1Ext: ApexSamplingPortP1 + Sized {
-        /// Returns Err(Error::InvalidConfig) if sampling port with name does not exists or
-        /// if the message size of the found sampling port is different than MSG_SIZE
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different `MSG_SIZE`
         fn get_const_sampling_port_source<const MSG_SIZE: MessageSize>(
             name: Name,
         ) -> Result<ConstSamplingPortSource<MSG_SIZE, Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if sampling port with name does not exists or
-        /// if the message size of the found sampling port is different than MSG_SIZE
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different `MSG_SIZE`
         fn get_const_sampling_port_destination<const MSG_SIZE: MessageSize>(
             name: Name,
         ) -> Result<ConstSamplingPortDestination<MSG_SIZE, Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if sampling port with name does not exists
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
         fn get_sampling_port_source(name: Name) -> Result<SamplingPortSource<Self>, Error>;
 
-        /// Returns Err(Error::InvalidConfig) if sampling port with name does not exists
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
         fn get_sampling_port_destination(
             name: Name,
         ) -> Result<SamplingPortDestination<Self>, Error>;
@@ -318,13 +529,14 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.6.2.1.5 this can only fail if the sampling_port_id
             //  does not exist in the current partition.
             // But since we retrieve the sampling_port_id directly from the hypervisor
-            //  there is no possible way for it not existing
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
             let SamplingPortStatus {
                 refresh_period: _,
                 max_message_size: msg_size,
                 port_direction,
                 ..
-            } = S::get_sampling_port_status(id).unwrap().into();
+            } = S::get_sampling_port_status(id)?.into();
 
             if port_direction != PortDirection::Source {
                 return Err(Error::InvalidConfig);
@@ -345,7 +557,8 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.6.2.1.5 this can only fail if the sampling_port_id
             //  does not exist in the current partition.
             // But since we retrieve the sampling_port_id directly from the hypervisor
-            //  there is no possible way for it not existing
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
             let SamplingPortStatus {
                 refresh_period,
                 max_message_size: msg_size,
@@ -362,13 +575,18 @@ pub mod abstraction {
                 id,
                 msg_size,
                 // According to ARINC653P1-5 3.6.2.1.1 the refresh_period defined during
-                //  COLD/WARM-Start is always positive, hence this unwrap cannot fail
-                refresh: refresh_period.unwrap_duration(),
+                //  COLD/WARM-Start is always positive, hence this can only fail for a faulty hypervisor
+                refresh: refresh_period.try_duration()?,
             })
         }
     }
 
     impl<S: ApexSamplingPortP4Ext> SamplingPortSource<S> {
+        /// Checked sampling port send from specified byte buffer
+        ///
+        /// # Errors
+        /// - [Error::InvalidConfig]: the `buffer` is longer than the `max_message_size` specified for this port
+        /// - [Error::InvalidParam]: `buffer` length is zero
         pub fn send(&self, buffer: &[ApexByte]) -> Result<(), Error> {
             buffer.validate_write(self.msg_size)?;
             S::sampling_port_send_unchecked(self.id, buffer)
@@ -384,20 +602,37 @@ pub mod abstraction {
     }
 
     impl<S: ApexSamplingPortP1Ext> SamplingPortSource<S> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
         pub fn from_name(name: Name) -> Result<SamplingPortSource<S>, Error> {
             S::get_sampling_port_source(name)
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this sampling port does not exist anymore
+        pub fn try_status(&self) -> Result<SamplingPortStatus, Error> {
+            Ok(S::get_sampling_port_status(self.id)?.into())
+        }
+
+        /// # Panics
+        /// if this sampling port does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> SamplingPortStatus {
             // According to ARINC653P1-5 3.6.2.1.5 this can only fail if the sampling_port_id
             //  does not exist in the current partition.
             // But since we retrieve the sampling_port_id directly from the hypervisor
             //  there is no possible way for it to not exist
-            S::get_sampling_port_status(self.id).unwrap().into()
+            self.try_status().unwrap()
         }
     }
 
     impl<S: ApexSamplingPortP4Ext> SamplingPortDestination<S> {
+        /// Checked sampling port receive into specified byte buffer
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: there is no message in the port
+        /// - [Error::ReadError]: provided `buffer` is too small for this port's `max_message_size`
         pub fn receive<'a>(
             &self,
             buffer: &'a mut [ApexByte],
@@ -406,6 +641,21 @@ pub mod abstraction {
             unsafe { S::sampling_port_receive_unchecked(self.id, buffer) }
         }
 
+        /// Checked sampling port receive into an owned [Message]
+        ///
+        /// `N` must be named and at least the `max_message_size` of this port.
+        /// Prefer [ConstSamplingPortDestination::receive_owned] if the size is known at compile time.
+        ///
+        /// # Errors
+        /// - [Error::ReadError]: `N` is smaller than this port's `max_message_size`
+        /// - errors of [SamplingPortDestination::receive]
+        pub fn receive_owned<const N: usize>(&self) -> Result<(Validity, Message<N>), Error> {
+            let mut data = [0; N];
+            let (validity, msg) = self.receive(&mut data)?;
+            let len = msg.len();
+            Ok((validity, Message::new(data, len)))
+        }
+
         pub const fn id(&self) -> SamplingPortId {
             self.id
         }
@@ -420,20 +670,38 @@ pub mod abstraction {
     }
 
     impl<S: ApexSamplingPortP1Ext> SamplingPortDestination<S> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: sampling port with `name` does not exist
+        /// - [Error::InvalidConfig]: sampling port with `name` has a different direction
         pub fn from_name(name: Name) -> Result<SamplingPortDestination<S>, Error> {
             S::get_sampling_port_destination(name)
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this sampling port does not exist anymore
+        pub fn try_status(&self) -> Result<SamplingPortStatus, Error> {
+            Ok(S::get_sampling_port_status(self.id)?.into())
+        }
+
+        /// # Panics
+        /// if this sampling port does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> SamplingPortStatus {
             // According to ARINC653P1-5 3.6.2.1.5 this can only fail if the sampling_port_id
             //  does not exist in the current partition.
             // But since we retrieve the sampling_port_id directly from the hypervisor
             //  there is no possible way for it not existing
-            S::get_sampling_port_status(self.id).unwrap().into()
+            self.try_status().unwrap()
         }
     }
 
     impl<S: ApexSamplingPortP4Ext> StartContext<S> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size or direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the port size is invalid
         pub fn create_const_sampling_port_source<const MSG_SIZE: MessageSize>(
             &mut self,
             name: Name,
@@ -441,6 +709,13 @@ pub mod abstraction {
             let port = self.create_sampling_port_source(name, MSG_SIZE)?;
             Ok(ConstSamplingPortSource(port))
         }
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size or direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the port size is invalid
+        /// - [Error::InvalidConfig]: `refresh` is invalid
         pub fn create_const_sampling_port_destination<const MSG_SIZE: MessageSize>(
             &mut self,
             name: Name,
@@ -450,6 +725,42 @@ pub mod abstraction {
             Ok(ConstSamplingPortDestination(port))
         }
 
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the size of `T` or the direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the size of `T` is invalid
+        pub fn create_typed_sampling_port_source<T: Pod>(
+            &mut self,
+            name: Name,
+        ) -> Result<TypedSamplingPortSource<T, S>, Error> {
+            let port = self.create_sampling_port_source(name, T::SIZE)?;
+            Ok(TypedSamplingPortSource(port, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the size of `T` or the direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the size of `T` is invalid
+        /// - [Error::InvalidConfig]: `refresh` is invalid
+        pub fn create_typed_sampling_port_destination<T: Pod>(
+            &mut self,
+            name: Name,
+            refresh: Duration,
+        ) -> Result<TypedSamplingPortDestination<T, S>, Error> {
+            let port = self.create_sampling_port_destination(name, T::SIZE, refresh)?;
+            Ok(TypedSamplingPortDestination(port, PhantomData))
+        }
+
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size or direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the port size is invalid
         pub fn create_sampling_port_source(
             &mut self,
             name: Name,
@@ -470,6 +781,13 @@ pub mod abstraction {
                 msg_size,
             })
         }
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: no sampling port with `name` is configured for this partition
+        /// - [Error::InvalidConfig]: the port size or direction does not match the configuration
+        /// - [Error::NoAction]: sampling port with `name` was already created
+        /// - [Error::InvalidParam]: the port size is invalid
+        /// - [Error::InvalidConfig]: `refresh` is invalid
         pub fn create_sampling_port_destination(
             &mut self,
             name: Name,
@@ -11,8 +11,15 @@ pub mod basic {
     pub type ScheduleId = ApexLongInteger;
 
     pub trait ApexScheduleP2 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: schedule with `schedule_id` does not exist
+        /// - [ErrorReturnCode::InvalidMode]: our current partition is not a system partition or has no permission to switch schedules
         fn set_module_schedule(schedule_id: ScheduleId) -> Result<(), ErrorReturnCode>;
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidMode]: our current partition has no permission to get the schedule status
         fn get_module_schedule_status() -> Result<ApexScheduleStatus, ErrorReturnCode>;
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: schedule with `schedule_name` does not exist
         fn get_module_schedule_id(
             schedule_name: ScheduleName,
         ) -> Result<ScheduleId, ErrorReturnCode>;
@@ -33,11 +40,23 @@ pub mod abstraction {
     pub use super::basic::{ScheduleId, ScheduleName};
     use crate::prelude::*;
 
+    /// Status of the module schedules, which are identified by `S`
     #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct ScheduleStatus {
+    pub struct ScheduleStatus<S = ScheduleId> {
         pub time_of_last_schedule_switch: SystemTime,
-        pub current_schedule: ScheduleId,
-        pub next_schedule: ScheduleId,
+        pub current_schedule: S,
+        pub next_schedule: S,
+    }
+
+    impl<S> ScheduleStatus<S> {
+        /// Identifies the current and next schedule by `f`
+        pub fn map<T>(self, mut f: impl FnMut(S) -> T) -> ScheduleStatus<T> {
+            ScheduleStatus {
+                time_of_last_schedule_switch: self.time_of_last_schedule_switch,
+                current_schedule: f(self.current_schedule),
+                next_schedule: f(self.next_schedule),
+            }
+        }
     }
 
     impl From<ApexScheduleStatus> for ScheduleStatus {
@@ -51,8 +70,15 @@ pub mod abstraction {
     }
 
     pub trait ApexScheduleP2Ext: ApexScheduleP2 + Sized {
+        /// # Errors
+        /// - [Error::InvalidConfig]: schedule with `schedule_id` does not exist
+        /// - [Error::InvalidMode]: our current partition is not a system partition or has no permission to switch schedules
         fn set_module_schedule(schedule_id: ScheduleId) -> Result<(), Error>;
+        /// # Errors
+        /// - [Error::InvalidMode]: our current partition has no permission to get the schedule status
         fn get_module_schedule_status() -> Result<ScheduleStatus, Error>;
+        /// # Errors
+        /// - [Error::InvalidConfig]: schedule with `schedule_name` does not exist
         fn get_module_schedule_id(schedule_name: ScheduleName) -> Result<ScheduleId, Error>;
     }
 
@@ -55,6 +55,13 @@ pub mod basic {
     }
 
     pub trait ApexSemaphoreP1 {
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: not enough memory is available
+        /// - [ErrorReturnCode::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_SEMAPHORES](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_SEMAPHORES) was reached
+        /// - [ErrorReturnCode::NoAction]: a semaphore with given `semaphore_name` already exists in this partition
+        /// - [ErrorReturnCode::InvalidParam]: `current_value` or `maximum_value` is out of range
+        /// - [ErrorReturnCode::InvalidParam]: `current_value` is greater than `maximum_value`
+        /// - [ErrorReturnCode::InvalidMode]: our current operating mode is [OperatingMode::Normal](crate::prelude::OperatingMode::Normal)
         // Only during Warm/Cold-Start
         fn create_semaphore(
             semaphore_name: SemaphoreName,
@@ -63,15 +70,28 @@ pub mod basic {
             queuing_discipline: QueuingDiscipline,
         ) -> Result<SemaphoreId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: semaphore with `semaphore_id` does not exist
+        /// - [ErrorReturnCode::InvalidParam]: `time_out` is invalid
+        /// - [ErrorReturnCode::InvalidMode]: current process holds a mutex or is an error handler and `time_out` is not instant
+        /// - [ErrorReturnCode::NotAvailable]: semaphore is not available and `time_out` is instant
+        /// - [ErrorReturnCode::TimedOut]: the `time_out` expired
         fn wait_semaphore(
             semaphore_id: SemaphoreId,
             time_out: ApexSystemTime,
         ) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: semaphore with `semaphore_id` does not exist
+        /// - [ErrorReturnCode::NoAction]: semaphore is already at its maximum value
         fn signal_semaphore(semaphore_id: SemaphoreId) -> Result<(), ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidConfig]: semaphore with `semaphore_name` does not exist
         fn get_semaphore_id(semaphore_name: SemaphoreName) -> Result<SemaphoreId, ErrorReturnCode>;
 
+        /// # Errors
+        /// - [ErrorReturnCode::InvalidParam]: semaphore with `semaphore_id` does not exist
         fn get_semaphore_status(
             semaphore_id: SemaphoreId,
         ) -> Result<SemaphoreStatus, ErrorReturnCode>;
@@ -106,6 +126,8 @@ pub mod abstraction {
     }
 
     pub trait ApexSemaphoreP1Ext: ApexSemaphoreP1 + Sized {
+        /// # Errors
+        /// - [Error::InvalidConfig]: semaphore with `name` does not exist
         fn get_semaphore(name: Name) -> Result<Semaphore<Self>, Error>;
     }
 
@@ -115,8 +137,9 @@ pub mod abstraction {
             // According to ARINC653P1-5 3.7.2.3.5  this can only fail if the semaphore_id
             //  does not exist in the current partition.
             // But since we retrieve the semaphore_id directly from the hypervisor
-            //  there is no possible way for it not existing
-            let status = S::get_semaphore_status(id).unwrap();
+            //  there is no possible way for it not existing,
+            //  hence an error can only stem from a faulty hypervisor
+            let status = S::get_semaphore_status(id)?;
 
             Ok(Semaphore {
                 _b: Default::default(),
@@ -127,6 +150,8 @@ pub mod abstraction {
     }
 
     impl<S: ApexSemaphoreP1> Semaphore<S> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: semaphore with `name` does not exist
         pub fn from_name(name: Name) -> Result<Semaphore<S>, Error> {
             S::get_semaphore(name)
         }
@@ -139,30 +164,93 @@ pub mod abstraction {
             self.maximum
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex or is an error handler and `timeout` is not instant
+        /// - [Error::NotAvailable]: semaphore is not available and `timeout` is instant
+        /// - [Error::TimedOut]: the `timeout` expired
         pub fn wait(&self, timeout: SystemTime) -> Result<(), Error> {
             S::wait_semaphore(self.id, timeout.into())?;
             Ok(())
         }
 
+        /// # Errors
+        /// - [Error::NoAction]: semaphore is already at its maximum value
         pub fn signal(&self) -> Result<(), Error> {
             S::signal_semaphore(self.id)?;
             Ok(())
         }
 
+        /// [wait](Self::wait) on this semaphore, [signal](Self::signal) it again when the returned permit is dropped
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `timeout` is invalid
+        /// - [Error::InvalidMode]: current process holds a mutex or is an error handler and `timeout` is not instant
+        /// - [Error::NotAvailable]: semaphore is not available and `timeout` is instant
+        /// - [Error::TimedOut]: the `timeout` expired
+        pub fn acquire(&self, timeout: SystemTime) -> Result<SemaphorePermit<'_, S>, Error> {
+            self.wait(timeout)?;
+            Ok(SemaphorePermit { semaphore: self })
+        }
+
+        /// # Errors
+        /// - [Error::InvalidParam]: this semaphore does not exist anymore
+        pub fn try_current(&self) -> Result<SemaphoreValue, Error> {
+            Ok(self.try_status()?.current_value)
+        }
+
+        /// # Panics
+        /// if this semaphore does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn current(&self) -> SemaphoreValue {
             self.status().current_value
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this semaphore does not exist anymore
+        pub fn try_status(&self) -> Result<SemaphoreStatus, Error> {
+            Ok(S::get_semaphore_status(self.id)?)
+        }
+
+        /// # Panics
+        /// if this semaphore does not exist anymore
+        #[cfg(not(feature = "panic-free"))]
         pub fn status(&self) -> SemaphoreStatus {
             // According to ARINC653P1-5 3.7.2.3.5  this can only fail if the semaphore_id
             //  does not exist in the current partition.
             // But since we retrieve the semaphore_id directly from the hypervisor
             //  there is no possible way for it not existing
-            S::get_semaphore_status(self.id).unwrap()
+            self.try_status().unwrap()
+        }
+    }
+
+    /// Permit of a [Semaphore], signaling the semaphore on drop
+    #[must_use = "the semaphore is signaled immediately if the permit is dropped"]
+    #[derive(Debug)]
+    pub struct SemaphorePermit<'a, S: ApexSemaphoreP1> {
+        semaphore: &'a Semaphore<S>,
+    }
+
+    impl<S: ApexSemaphoreP1> SemaphorePermit<'_, S> {
+        pub fn semaphore(&self) -> &Semaphore<S> {
+            self.semaphore
+        }
+    }
+
+    impl<S: ApexSemaphoreP1> Drop for SemaphorePermit<'_, S> {
+        fn drop(&mut self) {
+            // Can not exceed the maximum, as the value was decremented by this permit
+            let _ = self.semaphore.signal();
         }
     }
 
     impl<S: ApexSemaphoreP1> StartContext<S> {
+        /// # Errors
+        /// - [Error::InvalidConfig]: not enough memory is available
+        /// - [Error::InvalidConfig]: [ApexLimits::SYSTEM_LIMIT_NUMBER_OF_SEMAPHORES](crate::apex::limits::ApexLimits::SYSTEM_LIMIT_NUMBER_OF_SEMAPHORES) was reached
+        /// - [Error::NoAction]: a semaphore with given `name` already exists in this partition
+        /// - [Error::InvalidParam]: `current` or `maximum` is out of range
+        /// - [Error::InvalidParam]: `current` is greater than `maximum`
         pub fn create_semaphore(
             &mut self,
             name: Name,
@@ -45,6 +45,7 @@ pub mod basic {
 
 /// abstractions for ARINC653P1-5 3.4.2 time
 pub mod abstraction {
+    use core::ops::{Add, AddAssign, Sub, SubAssign};
     use core::time::Duration;
 
     use super::basic::{ApexSystemTime, ApexTimeP1, ApexTimeP4, INFINITE_TIME_VALUE};
@@ -71,8 +72,18 @@ pub mod abstraction {
             time.into()
         }
 
+        /// # Errors
+        /// - [Error::InvalidParam]: this SystemTime is [SystemTime::Infinite]
+        pub fn try_duration(self) -> Result<Duration, Error> {
+            match self {
+                SystemTime::Normal(time) => Ok(time),
+                SystemTime::Infinite => Err(Error::InvalidParam),
+            }
+        }
+
         /// # Panics
         /// If this SystemTime is [SystemTime::Infinite]
+        #[cfg(not(feature = "panic-free"))]
         pub fn unwrap_duration(self) -> Duration {
             if let SystemTime::Normal(time) = self {
                 return time;
@@ -131,6 +142,140 @@ pub mod abstraction {
         }
     }
 
+    /// Point in time measured since the start of the core module
+    ///
+    /// Obtained via [ApexTimeP4Ext::now].
+    /// Unlike [SystemTime] this is never infinite, which allows for comparison and arithmetic.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Instant(Duration);
+
+    impl Instant {
+        /// Start of the core module
+        pub const ZERO: Instant = Instant(Duration::ZERO);
+
+        pub const fn from_duration(since_start: Duration) -> Self {
+            Instant(since_start)
+        }
+
+        /// Time passed between the start of the core module and this instant
+        pub const fn as_duration(&self) -> Duration {
+            self.0
+        }
+
+        /// Time passed between `earlier` and this instant
+        ///
+        /// Returns zero if `earlier` is later than this instant.
+        pub fn duration_since(&self, earlier: Instant) -> Duration {
+            self.0.saturating_sub(earlier.0)
+        }
+
+        /// Time passed between `earlier` and this instant
+        ///
+        /// Returns [None] if `earlier` is later than this instant.
+        pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+            self.0.checked_sub(earlier.0)
+        }
+
+        /// Time passed since this instant
+        ///
+        /// Returns zero if this instant lies in the future.
+        ///
+        /// # Errors
+        /// - [Error::NotAvailable]: see [ApexTimeP4Ext::now]
+        pub fn elapsed<T: ApexTimeP4Ext>(&self) -> Result<Duration, Error> {
+            Ok(T::now()?.duration_since(*self))
+        }
+
+        pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+            self.0.checked_add(duration).map(Instant)
+        }
+
+        pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+            self.0.checked_sub(duration).map(Instant)
+        }
+
+        pub fn saturating_add(&self, duration: Duration) -> Instant {
+            Instant(self.0.saturating_add(duration))
+        }
+
+        pub fn saturating_sub(&self, duration: Duration) -> Instant {
+            Instant(self.0.saturating_sub(duration))
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Instant;
+
+        /// # Panics
+        /// If the resulting instant overflows
+        fn add(self, rhs: Duration) -> Self::Output {
+            Instant(self.0 + rhs)
+        }
+    }
+
+    impl AddAssign<Duration> for Instant {
+        fn add_assign(&mut self, rhs: Duration) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub<Duration> for Instant {
+        type Output = Instant;
+
+        /// # Panics
+        /// If the resulting instant lies before [Instant::ZERO]
+        fn sub(self, rhs: Duration) -> Self::Output {
+            Instant(self.0 - rhs)
+        }
+    }
+
+    impl SubAssign<Duration> for Instant {
+        fn sub_assign(&mut self, rhs: Duration) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Sub<Instant> for Instant {
+        type Output = Duration;
+
+        /// Saturates to zero like [Instant::duration_since]
+        fn sub(self, rhs: Instant) -> Self::Output {
+            self.duration_since(rhs)
+        }
+    }
+
+    impl From<ApexSystemTime> for Instant {
+        /// Negative values, which would be infinite as a [SystemTime], saturate to [Instant::ZERO]
+        fn from(time: ApexSystemTime) -> Self {
+            Instant(Duration::from_nanos(u64::try_from(time).unwrap_or(0)))
+        }
+    }
+
+    impl From<Instant> for ApexSystemTime {
+        /// Instants beyond [ApexSystemTime::MAX] nanoseconds saturate to [ApexSystemTime::MAX]
+        fn from(time: Instant) -> Self {
+            ApexSystemTime::try_from(time.0.as_nanos()).unwrap_or(ApexSystemTime::MAX)
+        }
+    }
+
+    impl From<Instant> for SystemTime {
+        fn from(time: Instant) -> Self {
+            SystemTime::Normal(time.0)
+        }
+    }
+
+    impl TryFrom<SystemTime> for Instant {
+        type Error = Error;
+
+        fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+            match time {
+                SystemTime::Normal(time) => Ok(Instant(time)),
+                SystemTime::Infinite => Err(Error::InvalidParam),
+            }
+        }
+    }
+
     /// Free extra functions for implementer of [ApexTimeP4]
     pub trait ApexTimeP4Ext: ApexTimeP4 + Sized {
         /// wait until next release
@@ -143,6 +288,28 @@ pub mod abstraction {
         fn periodic_wait() -> Result<(), Error>;
 
         fn get_time() -> SystemTime;
+
+        /// Current time as an [Instant]
+        ///
+        /// # Errors
+        /// - [Error::NotAvailable]: the hypervisor returned an infinite or negative time
+        fn now() -> Result<Instant, Error>;
+
+        /// [Instant] at which `timeout` elapsed from now
+        ///
+        /// # Errors
+        /// - [Error::NotAvailable]: see [ApexTimeP4Ext::now]
+        fn deadline_from_now(timeout: Duration) -> Result<Instant, Error> {
+            Ok(Self::now()?.saturating_add(timeout))
+        }
+
+        /// Time left until `deadline`, zero if it already passed
+        ///
+        /// # Errors
+        /// - [Error::NotAvailable]: see [ApexTimeP4Ext::now]
+        fn remaining_until(deadline: Instant) -> Result<Duration, Error> {
+            Ok(deadline.duration_since(Self::now()?))
+        }
     }
 
     impl<T: ApexTimeP4> ApexTimeP4Ext for T {
@@ -154,6 +321,10 @@ pub mod abstraction {
         fn get_time() -> SystemTime {
             T::get_time().into()
         }
+
+        fn now() -> Result<Instant, Error> {
+            Instant::try_from(<Self as ApexTimeP4Ext>::get_time()).map_err(|_| Error::NotAvailable)
+        }
     }
 
     /// Free extra functions for implementer of [ApexTimeP1]
@@ -186,3 +357,72 @@ pub mod abstraction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::abstraction::*;
+    use super::basic::{ApexSystemTime, INFINITE_TIME_VALUE};
+    use crate::prelude::Error;
+
+    const SECOND: Instant = Instant::from_duration(Duration::from_secs(1));
+
+    #[test]
+    fn duration_since_saturates() {
+        let later = SECOND + Duration::from_millis(500);
+        assert_eq!(later.duration_since(SECOND), Duration::from_millis(500));
+        assert_eq!(SECOND.duration_since(later), Duration::ZERO);
+        assert_eq!(SECOND - later, Duration::ZERO);
+        assert_eq!(
+            later.checked_duration_since(SECOND),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(SECOND.checked_duration_since(later), None);
+    }
+
+    #[test]
+    fn checked_and_saturating_arithmetic() {
+        let max = Instant::from_duration(Duration::MAX);
+        assert_eq!(max.checked_add(Duration::from_nanos(1)), None);
+        assert_eq!(max.saturating_add(Duration::from_secs(1)), max);
+        assert_eq!(SECOND.checked_sub(Duration::from_secs(2)), None);
+        assert_eq!(SECOND.saturating_sub(Duration::from_secs(2)), Instant::ZERO);
+        assert_eq!(
+            SECOND.checked_add(Duration::from_secs(1)),
+            Some(Instant::from_duration(Duration::from_secs(2)))
+        );
+
+        let mut instant = SECOND;
+        instant += Duration::from_secs(2);
+        instant -= Duration::from_secs(1);
+        assert_eq!(instant, Instant::from_duration(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn apex_system_time_conversion() {
+        assert_eq!(Instant::from(1_000_000_000 as ApexSystemTime), SECOND);
+        assert_eq!(Instant::from(INFINITE_TIME_VALUE), Instant::ZERO);
+        assert_eq!(Instant::from(-5 as ApexSystemTime), Instant::ZERO);
+
+        assert_eq!(ApexSystemTime::from(SECOND), 1_000_000_000);
+        let max = Instant::from_duration(Duration::MAX);
+        assert_eq!(ApexSystemTime::from(max), ApexSystemTime::MAX);
+    }
+
+    #[test]
+    fn system_time_conversion() {
+        assert_eq!(
+            SystemTime::from(SECOND),
+            SystemTime::Normal(Duration::from_secs(1))
+        );
+        assert_eq!(
+            Instant::try_from(SystemTime::Normal(Duration::from_secs(1))),
+            Ok(SECOND)
+        );
+        assert_eq!(
+            Instant::try_from(SystemTime::Infinite),
+            Err(Error::InvalidParam)
+        );
+    }
+}
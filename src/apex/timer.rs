@@ -0,0 +1,443 @@
+/// abstraction for software timers within a partition
+pub mod abstraction {
+    use core::time::Duration;
+
+    use crate::apex::buffer::basic::ApexBufferP1;
+    use crate::apex::error::abstraction::with_message;
+    use crate::apex::event::basic::ApexEventP1;
+    use crate::apex::mutex::basic::ApexMutexP1;
+    use crate::apex::time::basic::ApexSystemTime;
+    use crate::prelude::*;
+
+    /// Identifier of a timer registered at a [TimerService]
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TimerId {
+        index: u32,
+        generation: u32,
+    }
+
+    // TimerId consists of two integers without padding
+    unsafe impl Pod for TimerId {}
+
+    /// Message sent to the target buffer of a timer once it expires
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TimerExpiry {
+        /// Timer which expired, as returned when it was started
+        pub timer: TimerId,
+        /// Time at which the timer expired
+        pub deadline: ApexSystemTime,
+        /// Number of expiries of a periodic timer which were lost since the previous one,
+        /// because the target buffer was full or the timer process ran too late
+        pub missed: u64,
+    }
+
+    // TimerExpiry consists of a TimerId and two integers, all 8 bytes large
+    unsafe impl Pod for TimerExpiry {}
+
+    #[derive(Debug)]
+    struct Timer<H: ApexBufferP1> {
+        generation: u32,
+        deadline: Instant,
+        period: Option<Duration>,
+        /// Lost expiries since the last one sent
+        missed: u64,
+        target: TypedBuffer<TimerExpiry, H>,
+    }
+
+    #[derive(Debug)]
+    struct Timers<H: ApexBufferP1, const N: usize> {
+        slots: [Option<Timer<H>>; N],
+        next_generation: u32,
+    }
+
+    /// Service for up to `N` one-shot and periodic software timers
+    ///
+    /// The timers are handled by a dedicated process calling [TimerService::run].
+    /// Other processes register timers with a target [TypedBuffer],
+    /// to which a [TimerExpiry] is sent whenever a timer expires.
+    /// Registering and canceling wakes the timer process up via an [Event].
+    ///
+    /// Expiries are sent without waiting, so they are lost if the target buffer is full.
+    /// Lost expiries of periodic timers are counted in [TimerExpiry::missed],
+    /// while failures of the timer process and lost one-shot expiries are reported to the health monitor.
+    #[derive(Debug)]
+    pub struct TimerService<H, const N: usize>
+    where
+        H: ApexMutexP1 + ApexEventP1 + ApexBufferP1 + ApexTimeP1Ext + ApexErrorP4Ext,
+    {
+        timers: ApexMutex<H, Timers<H, N>>,
+        wakeup: Event<H>,
+    }
+
+    impl<H, const N: usize> TimerService<H, N>
+    where
+        H: ApexMutexP1 + ApexEventP1 + ApexBufferP1 + ApexTimeP1Ext + ApexErrorP4Ext,
+    {
+        /// Delay before the timer process retries after a failure
+        pub const RETRY_INTERVAL: Duration = Duration::from_millis(10);
+
+        /// `wakeup` must only be used by this service
+        ///
+        /// # Safety
+        /// `mutex` must only be used by this service, see [ApexMutex::new]
+        pub unsafe fn new(mutex: Mutex<H>, wakeup: Event<H>) -> Self {
+            let timers = Timers {
+                slots: [const { None }; N],
+                next_generation: 0,
+            };
+            Self {
+                // This is safe because the caller guarantees that `mutex` is only used by this service
+                timers: unsafe { ApexMutex::new(mutex, timers) },
+                wakeup,
+            }
+        }
+
+        /// Sends a single [TimerExpiry] to `target` after `timeout`
+        ///
+        /// # Errors
+        /// - [Error::NotAvailable]: all `N` timers are in use
+        /// - [Error::NotAvailable]: the current time is not available
+        /// - errors of [ApexMutex::lock]
+        pub fn start_one_shot(
+            &self,
+            timeout: Duration,
+            target: &TypedBuffer<TimerExpiry, H>,
+        ) -> Result<TimerId, Error> {
+            self.register(timeout, None, target)
+        }
+
+        /// Sends a [TimerExpiry] to `target` once every `period`, until the timer is canceled
+        ///
+        /// Periods are skipped if the timer process runs too late.
+        /// These and the expiries which did not fit into `target`
+        /// are counted in [TimerExpiry::missed] of the next expiry sent.
+        ///
+        /// # Errors
+        /// - [Error::InvalidParam]: `period` is zero
+        /// - [Error::NotAvailable]: all `N` timers are in use
+        /// - [Error::NotAvailable]: the current time is not available
+        /// - errors of [ApexMutex::lock]
+        pub fn start_periodic(
+            &self,
+            period: Duration,
+            target: &TypedBuffer<TimerExpiry, H>,
+        ) -> Result<TimerId, Error> {
+            if period.is_zero() {
+                return Err(Error::InvalidParam);
+            }
+            self.register(period, Some(period), target)
+        }
+
+        /// Stops the timer with `id`
+        ///
+        /// An expiry which was sent before canceling may still be in the target buffer.
+        ///
+        /// # Errors
+        /// - [Error::NoAction]: the timer already expired or was canceled
+        /// - errors of [ApexMutex::lock]
+        pub fn cancel(&self, id: TimerId) -> Result<(), Error> {
+            let mut timers = self.timers.lock(SystemTime::Infinite)?;
+            let slot = timers
+                .slots
+                .get_mut(id.index as usize)
+                .filter(|slot| matches!(slot, Some(t) if t.generation == id.generation))
+                .ok_or(Error::NoAction)?;
+            *slot = None;
+            drop(timers);
+            // Only fails for a faulty hypervisor, the timer process then wakes up at the next deadline
+            let _ = self.wakeup.try_set();
+            Ok(())
+        }
+
+        /// Body of the timer process, which sends the expiries of all timers
+        ///
+        /// If the timers can not be served or the process can not wait for the next deadline,
+        /// this is reported and retried after [TimerService::RETRY_INTERVAL].
+        /// If not even that delay is possible, an application error is raised.
+        pub fn run(&self) -> ! {
+            loop {
+                // Reset before checking the timers, so no registration in between is missed
+                let _ = self.wakeup.try_reset();
+                let timeout = match self.expire() {
+                    Ok(Some(remaining)) => SystemTime::Normal(remaining),
+                    Ok(None) => SystemTime::Infinite,
+                    Err(e) => {
+                        Self::report(format_args!("timers not served: {e:?}"));
+                        SystemTime::Normal(Self::RETRY_INTERVAL)
+                    }
+                };
+                match self.wakeup.wait(timeout) {
+                    // Timing out is the regular way of reaching the next deadline,
+                    // an instant timeout fails if the event is down
+                    Ok(()) | Err(Error::TimedOut | Error::NotAvailable) => {}
+                    Err(e) => {
+                        Self::report(format_args!("waiting for timers failed: {e:?}"));
+                        if <H as ApexTimeP1Ext>::timed_wait(Self::RETRY_INTERVAL).is_err() {
+                            let msg = b"timer process can not wait";
+                            let _ = <H as ApexErrorP4Ext>::raise_application_error(msg);
+                        }
+                    }
+                }
+            }
+        }
+
+        fn register(
+            &self,
+            timeout: Duration,
+            period: Option<Duration>,
+            target: &TypedBuffer<TimerExpiry, H>,
+        ) -> Result<TimerId, Error> {
+            let mut timers = self.timers.lock(SystemTime::Infinite)?;
+            let generation = timers.next_generation;
+            let (index, slot) = timers
+                .slots
+                .iter_mut()
+                .enumerate()
+                .find(|(_, slot)| slot.is_none())
+                .ok_or(Error::NotAvailable)?;
+            *slot = Some(Timer {
+                generation,
+                deadline: H::deadline_from_now(timeout)?,
+                period,
+                missed: 0,
+                target: target.clone(),
+            });
+            timers.next_generation = generation.wrapping_add(1);
+            drop(timers);
+            // Only fails for a faulty hypervisor, the timer process then wakes up at the next deadline
+            let _ = self.wakeup.try_set();
+            Ok(TimerId {
+                index: index as u32,
+                generation,
+            })
+        }
+
+        /// Sends the expiries of all expired timers, returning the time until the next deadline
+        pub(super) fn expire(&self) -> Result<Option<Duration>, Error> {
+            let mut timers = self.timers.lock(SystemTime::Infinite)?;
+            let now = H::now()?;
+            let mut next: Option<Instant> = None;
+            for (index, slot) in timers.slots.iter_mut().enumerate() {
+                let Some(timer) = slot else {
+                    continue;
+                };
+                if timer.deadline <= now {
+                    let expiry = TimerExpiry {
+                        timer: TimerId {
+                            index: index as u32,
+                            generation: timer.generation,
+                        },
+                        deadline: timer.deadline.into(),
+                        missed: timer.missed,
+                    };
+                    // Waiting is not allowed while holding the mutex, a full buffer loses the expiry
+                    let sent = timer
+                        .target
+                        .send(&expiry, SystemTime::Normal(Duration::ZERO))
+                        .is_ok();
+                    match timer.period {
+                        // Skip periods which already passed
+                        Some(period) => {
+                            let skipped =
+                                now.duration_since(timer.deadline).as_nanos() / period.as_nanos();
+                            let skip = (skipped + 1) * period.as_nanos();
+                            timer.deadline = timer.deadline.saturating_add(Duration::from_nanos(
+                                u64::try_from(skip).unwrap_or(u64::MAX),
+                            ));
+                            let lost = if sent {
+                                0
+                            } else {
+                                timer.missed.saturating_add(1)
+                            };
+                            timer.missed =
+                                lost.saturating_add(u64::try_from(skipped).unwrap_or(u64::MAX));
+                        }
+                        None => {
+                            if !sent {
+                                Self::report(format_args!("timer {index} expiry lost"));
+                            }
+                            *slot = None;
+                            continue;
+                        }
+                    }
+                }
+                next = Some(next.map_or(timer.deadline, |n| n.min(timer.deadline)));
+            }
+            Ok(next.map(|next| next.duration_since(now)))
+        }
+
+        fn report(failure: core::fmt::Arguments) {
+            // Reporting is best effort, the message can not be too large
+            let _ = with_message(failure, <H as ApexErrorP4Ext>::report_application_message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use crate::apex::mock::MockHypervisor;
+    use crate::prelude::*;
+
+    const MS: i64 = 1_000_000;
+    const INSTANT: SystemTime = SystemTime::Normal(Duration::ZERO);
+
+    fn setup<const N: usize>(
+        range: MessageRange,
+    ) -> (
+        TimerService<MockHypervisor, N>,
+        TypedBuffer<TimerExpiry, MockHypervisor>,
+    ) {
+        MockHypervisor::set_time(0);
+        MockHypervisor::take_messages();
+        // This is safe because the test is the only user of the context
+        let mut ctx = unsafe { StartContext::<MockHypervisor>::new() };
+        let mutex = ctx
+            .create_mutex(
+                Name::from_str("timers").unwrap(),
+                1,
+                QueuingDiscipline::Fifo,
+            )
+            .unwrap();
+        let wakeup = ctx.create_event(Name::from_str("wakeup").unwrap()).unwrap();
+        let target = ctx
+            .create_typed_buffer(
+                Name::from_str("target").unwrap(),
+                range,
+                QueuingDiscipline::Fifo,
+            )
+            .unwrap();
+        // This is safe because the mutex is only used by the service
+        let service = unsafe { TimerService::new(mutex, wakeup) };
+        (service, target)
+    }
+
+    #[test]
+    fn one_shot_expires_once() {
+        let (service, target) = setup::<2>(4);
+        let id = service
+            .start_one_shot(Duration::from_millis(10), &target)
+            .unwrap();
+
+        assert_eq!(service.expire(), Ok(Some(Duration::from_millis(10))));
+        assert_eq!(target.receive(INSTANT), Err(Error::NotAvailable));
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        assert_eq!(service.expire(), Ok(None));
+        let expiry = TimerExpiry {
+            timer: id,
+            deadline: 10 * MS,
+            missed: 0,
+        };
+        assert_eq!(target.receive(INSTANT), Ok(expiry));
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        assert_eq!(service.expire(), Ok(None));
+        assert_eq!(target.receive(INSTANT), Err(Error::NotAvailable));
+        assert_eq!(service.cancel(id), Err(Error::NoAction));
+    }
+
+    #[test]
+    fn periodic_counts_skipped_periods() {
+        let (service, target) = setup::<2>(4);
+        let id = service
+            .start_periodic(Duration::from_millis(10), &target)
+            .unwrap();
+
+        // The timer process runs late, missing the expiries at 20ms and 30ms
+        MockHypervisor::advance(Duration::from_millis(35));
+        assert_eq!(service.expire(), Ok(Some(Duration::from_millis(5))));
+        let expiry = target.receive(INSTANT).unwrap();
+        assert_eq!(
+            (expiry.timer, expiry.deadline, expiry.missed),
+            (id, 10 * MS, 0)
+        );
+
+        MockHypervisor::advance(Duration::from_millis(5));
+        assert_eq!(service.expire(), Ok(Some(Duration::from_millis(10))));
+        let expiry = target.receive(INSTANT).unwrap();
+        assert_eq!((expiry.deadline, expiry.missed), (40 * MS, 2));
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        service.expire().unwrap();
+        let expiry = target.receive(INSTANT).unwrap();
+        assert_eq!((expiry.deadline, expiry.missed), (50 * MS, 0));
+    }
+
+    #[test]
+    fn periodic_counts_expiries_lost_in_full_buffer() {
+        let (service, target) = setup::<1>(1);
+        service
+            .start_periodic(Duration::from_millis(10), &target)
+            .unwrap();
+
+        for _ in 0..3 {
+            MockHypervisor::advance(Duration::from_millis(10));
+            service.expire().unwrap();
+        }
+        let expiry = target.receive(INSTANT).unwrap();
+        assert_eq!((expiry.deadline, expiry.missed), (10 * MS, 0));
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        service.expire().unwrap();
+        let expiry = target.receive(INSTANT).unwrap();
+        assert_eq!((expiry.deadline, expiry.missed), (40 * MS, 2));
+        assert!(MockHypervisor::take_messages().is_empty());
+    }
+
+    #[test]
+    fn lost_one_shot_expiry_is_reported() {
+        let (service, target) = setup::<2>(1);
+        for _ in 0..2 {
+            service
+                .start_one_shot(Duration::from_millis(10), &target)
+                .unwrap();
+        }
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        assert_eq!(service.expire(), Ok(None));
+        assert_eq!(MockHypervisor::take_messages(), ["timer 1 expiry lost"]);
+    }
+
+    #[test]
+    fn stale_id_can_not_cancel_reused_slot() {
+        let (service, target) = setup::<1>(4);
+        let stale = service
+            .start_one_shot(Duration::from_millis(10), &target)
+            .unwrap();
+        assert_eq!(service.cancel(stale), Ok(()));
+        assert_eq!(service.cancel(stale), Err(Error::NoAction));
+
+        let id = service
+            .start_one_shot(Duration::from_millis(10), &target)
+            .unwrap();
+        assert_ne!(id, stale);
+        assert_eq!(service.cancel(stale), Err(Error::NoAction));
+
+        MockHypervisor::advance(Duration::from_millis(10));
+        service.expire().unwrap();
+        assert_eq!(target.receive(INSTANT).map(|e| e.timer), Ok(id));
+    }
+
+    #[test]
+    fn all_timers_in_use() {
+        let (service, target) = setup::<1>(4);
+        service
+            .start_periodic(Duration::from_millis(10), &target)
+            .unwrap();
+        assert_eq!(
+            service.start_one_shot(Duration::from_millis(10), &target),
+            Err(Error::NotAvailable)
+        );
+        assert_eq!(
+            service.start_periodic(Duration::ZERO, &target),
+            Err(Error::InvalidParam)
+        );
+    }
+}
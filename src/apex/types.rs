@@ -22,6 +22,7 @@ pub mod basic {
     #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[cfg_attr(feature = "strum", derive(strum::FromRepr))]
+    #[non_exhaustive]
     pub enum ErrorReturnCode {
         /// status of system unaffected by request
         NoAction = 1,
@@ -45,17 +46,37 @@ pub mod basic {
         /// - `0` => `Ok(())`
         /// - `1..=6` => `Err(Self)`
         /// - `7..` => `panic`
+        ///
+        /// Use [Error::from_return_code](super::abstraction::Error::from_return_code)
+        /// for handling unknown codes without panicking.
+        ///
+        /// # Errors
+        /// if `from` is a non-zero [ReturnCode]
+        ///
+        /// # Panics
+        /// if `from` is not a valid [ReturnCode]
+        #[cfg(not(feature = "panic-free"))]
         pub fn from(from: ReturnCode) -> Result<(), Self> {
+            if from == 0 {
+                return Ok(());
+            }
+            Err(Self::try_from(from).unwrap_or_else(|unexpected| panic!("{unexpected}")))
+        }
+    }
+
+    impl TryFrom<ReturnCode> for ErrorReturnCode {
+        type Error = ReturnCode;
+
+        fn try_from(value: ReturnCode) -> Result<Self, Self::Error> {
             use ErrorReturnCode::*;
-            match from {
-                0 => Ok(()),
-                1 => Err(NoAction),
-                2 => Err(NotAvailable),
-                3 => Err(InvalidParam),
-                4 => Err(InvalidConfig),
-                5 => Err(InvalidMode),
-                6 => Err(TimedOut),
-                unexpected => panic!("{unexpected}"),
+            match value {
+                1 => Ok(NoAction),
+                2 => Ok(NotAvailable),
+                3 => Ok(InvalidParam),
+                4 => Ok(InvalidConfig),
+                5 => Ok(InvalidMode),
+                6 => Ok(TimedOut),
+                _ => Err(value),
             }
         }
     }
@@ -109,19 +130,21 @@ pub mod basic {
 }
 
 pub mod abstraction {
+    use core::ops::Deref;
     use core::str::{FromStr, Utf8Error};
 
     // Reexport important basic-types for downstream-user
     pub use super::basic::{
-        ApexByte, ApexUnsigned, MessageRange, MessageSize, ProcessorCoreId, QueuingDiscipline,
-        CORE_AFFINITY_NO_PREFERENCE, MAX_NAME_LENGTH,
+        ApexByte, ApexUnsigned, ErrorReturnCode, MessageRange, MessageSize, ProcessorCoreId,
+        QueuingDiscipline, CORE_AFFINITY_NO_PREFERENCE, MAX_NAME_LENGTH,
     };
-    use super::basic::{ApexName, ErrorReturnCode};
+    use super::basic::{ApexLongInteger, ApexName, ReturnCode};
 
     /// Error Type used by abstracted functions.  
-    /// Includes all Variants of [ErrorReturnCode] plus a `WriteError` and `ReadError` variant
-    #[derive(Clone, Debug, PartialEq, Eq)]
+    /// Includes all Variants of [ErrorReturnCode] plus a `WriteError`, `ReadError` and `Uninitialized` variant
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
     pub enum Error {
         /// status of system unaffected by request
         NoAction,
@@ -139,6 +162,10 @@ pub mod abstraction {
         WriteError,
         /// buffer is too small
         ReadError,
+        /// resource was not initialized during the partition start
+        Uninitialized,
+        /// return code not defined by ARINC653
+        Unknown(ReturnCode),
     }
 
     impl From<ErrorReturnCode> for Error {
@@ -155,6 +182,243 @@ pub mod abstraction {
         }
     }
 
+    impl Error {
+        /// Convert a raw [ReturnCode] without panicking on codes not defined by ARINC653
+        ///
+        /// # Errors
+        /// - the [ErrorReturnCode] as [Error] if `rc` is one
+        /// - [Error::Unknown]: any other non-zero `rc`
+        pub fn from_return_code(rc: ReturnCode) -> Result<(), Error> {
+            if rc == 0 {
+                return Ok(());
+            }
+            Err(ErrorReturnCode::try_from(rc).map_or(Error::Unknown(rc), Error::from))
+        }
+    }
+
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            use Error::*;
+            match self {
+                NoAction => f.write_str("status of system unaffected by request"),
+                NotAvailable => f.write_str("resource required by request unavailable"),
+                InvalidParam => f.write_str("invalid parameter specified in request"),
+                InvalidConfig => f.write_str("parameter incompatible with configuration"),
+                InvalidMode => f.write_str("request incompatible with current mode"),
+                TimedOut => f.write_str("time-out tied up with request has expired"),
+                WriteError => f.write_str("buffer is too large"),
+                ReadError => f.write_str("buffer is too small"),
+                Uninitialized => {
+                    f.write_str("resource was not initialized during the partition start")
+                }
+                Unknown(rc) => write!(f, "unknown return code {rc}"),
+            }
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    /// APEX operation during which a [ContextError] occurred
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Operation {
+        /// Creation of an APEX object during the partition start
+        Create,
+        /// Lookup of an APEX object by its name
+        Lookup,
+        /// Query of the status of an APEX object
+        Status,
+        /// Sending a message via a queuing port
+        QueuingSend,
+        /// Receiving a message from a queuing port
+        QueuingReceive,
+        /// Clearing a queuing port
+        QueuingClear,
+        /// Writing a message to a sampling port
+        SamplingWrite,
+        /// Reading a message from a sampling port
+        SamplingRead,
+        /// Sending a message via a buffer
+        BufferSend,
+        /// Receiving a message from a buffer
+        BufferReceive,
+        /// Displaying a message on a blackboard
+        BlackboardDisplay,
+        /// Reading the message of a blackboard
+        BlackboardRead,
+        /// Clearing a blackboard
+        BlackboardClear,
+        /// Setting an event up
+        EventSet,
+        /// Resetting an event down
+        EventReset,
+        /// Waiting for an event
+        EventWait,
+        /// Waiting for a semaphore
+        SemaphoreWait,
+        /// Signaling a semaphore
+        SemaphoreSignal,
+        /// Acquiring a mutex
+        MutexAcquire,
+        /// Releasing a mutex
+        MutexRelease,
+        /// Starting a process
+        ProcessStart,
+        /// Stopping a process
+        ProcessStop,
+        /// Suspending a process
+        ProcessSuspend,
+        /// Resuming a process
+        ProcessResume,
+        /// Waiting for a delay
+        TimedWait,
+        /// Waiting for the next release point
+        PeriodicWait,
+        /// Replenishing the time budget
+        Replenish,
+        /// Changing the operating mode of the partition
+        SetPartitionMode,
+        /// Reporting a message to the health monitor
+        ReportMessage,
+    }
+
+    impl core::fmt::Display for Operation {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self, f)
+        }
+    }
+
+    /// APEX object affected by a [ContextError]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Object {
+        /// Partition wide operation or unknown object
+        Unspecified,
+        /// Name of a port, process or intra-partition communication object
+        Name(Name),
+        /// Id of a port, process or intra-partition communication object
+        Id(ApexLongInteger),
+    }
+
+    impl From<Name> for Object {
+        fn from(name: Name) -> Self {
+            Object::Name(name)
+        }
+    }
+
+    impl From<ApexLongInteger> for Object {
+        fn from(id: ApexLongInteger) -> Self {
+            Object::Id(id)
+        }
+    }
+
+    impl core::fmt::Display for Object {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Object::Unspecified => f.write_str("-"),
+                Object::Name(name) => f.write_str(name.to_str().unwrap_or("?")),
+                Object::Id(id) => write!(f, "#{id}"),
+            }
+        }
+    }
+
+    /// [Error] together with the failed [Operation] and the affected [Object]
+    ///
+    /// Functions of this crate return a plain [Error], the context is opt-in.
+    /// It is attached with [ContextExt::context] or by the `*_ctx` variants of the typed ports,
+    /// buffers and blackboards, events and mutexes,
+    /// like [TypedQueuingPortSender::send_ctx](crate::prelude::TypedQueuingPortSender::send_ctx).
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct ContextError {
+        /// Operation which failed
+        pub operation: Operation,
+        /// Object on which `operation` failed
+        pub object: Object,
+        /// Error returned by `operation`
+        pub error: Error,
+        /// [ErrorReturnCode] returned by the hypervisor
+        ///
+        /// [None] if `error` was detected by this crate before calling the hypervisor,
+        /// or if it was attached to a plain [Error] whose origin is unknown.
+        pub return_code: Option<ErrorReturnCode>,
+    }
+
+    impl ContextError {
+        /// [ContextError] of `error` returned by `operation` on `object`
+        pub fn new(operation: Operation, object: impl Into<Object>, error: Error) -> Self {
+            Self {
+                operation,
+                object: object.into(),
+                error,
+                return_code: None,
+            }
+        }
+
+        /// [ContextError] of the `return_code` the hypervisor returned for `operation` on `object`
+        pub fn from_return_code(
+            operation: Operation,
+            object: impl Into<Object>,
+            return_code: ErrorReturnCode,
+        ) -> Self {
+            Self {
+                operation,
+                object: object.into(),
+                error: return_code.into(),
+                return_code: Some(return_code),
+            }
+        }
+    }
+
+    impl From<ContextError> for Error {
+        fn from(e: ContextError) -> Self {
+            e.error
+        }
+    }
+
+    impl core::fmt::Display for ContextError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{} {}: {}", self.operation, self.object, self.error)
+        }
+    }
+
+    impl core::error::Error for ContextError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            Some(&self.error)
+        }
+    }
+
+    /// Attach an [Operation] and [Object] to an [Error] or [ErrorReturnCode]
+    pub trait ContextExt<T> {
+        /// # Errors
+        /// if `self` is an error, it is returned as [ContextError]
+        fn context(
+            self,
+            operation: Operation,
+            object: impl Into<Object>,
+        ) -> Result<T, ContextError>;
+    }
+
+    impl<T> ContextExt<T> for Result<T, Error> {
+        fn context(
+            self,
+            operation: Operation,
+            object: impl Into<Object>,
+        ) -> Result<T, ContextError> {
+            self.map_err(|e| ContextError::new(operation, object, e))
+        }
+    }
+
+    impl<T> ContextExt<T> for Result<T, ErrorReturnCode> {
+        fn context(
+            self,
+            operation: Operation,
+            object: impl Into<Object>,
+        ) -> Result<T, ContextError> {
+            self.map_err(|e| ContextError::from_return_code(operation, object, e))
+        }
+    }
+
     /// Convenient Abstraction Name Type  
     /// Uses [ApexName] internally
     #[derive(Clone, Debug, PartialEq, Eq)]
@@ -166,6 +430,8 @@ pub mod abstraction {
             Name(name)
         }
 
+        /// # Errors
+        /// if the name up to the first nul byte is not valid UTF-8
         pub fn to_str(&self) -> Result<&str, Utf8Error> {
             let nul_range_end = self
                 .0
@@ -200,10 +466,17 @@ pub mod abstraction {
     }
 
     pub trait BufferExt {
+        /// Validate a buffer to be at least as long as the given usize.
+        ///
+        /// # Errors
+        /// - [Error::ReadError]: the buffer is shorter than `size`
         fn validate_read(&mut self, size: MessageSize) -> Result<&mut Self, Error>;
 
-        /// Validate a buffer to be at most as long as the given usize.  
-        /// If not returns [Self] with the length of the passed buffer
+        /// Validate a buffer to be at most as long as the given usize.
+        ///
+        /// # Errors
+        /// - [Error::InvalidConfig]: the buffer is longer than `size`
+        /// - [Error::InvalidParam]: the buffer is empty
         fn validate_write(&self, size: MessageSize) -> Result<&Self, Error>;
     }
 
@@ -231,4 +504,118 @@ pub mod abstraction {
             Ok(self)
         }
     }
+
+    /// Owned message of at most `N` bytes
+    ///
+    /// Dereferences to the received bytes only.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Message<const N: usize> {
+        data: [ApexByte; N],
+        len: usize,
+    }
+
+    impl<const N: usize> Message<N> {
+        /// # Panics
+        /// if `len` is larger than `N`
+        pub(crate) fn new(data: [ApexByte; N], len: usize) -> Self {
+            assert!(len <= N);
+            Self { data, len }
+        }
+
+        /// The whole backing array together with the length of the message
+        pub fn into_inner(self) -> ([ApexByte; N], usize) {
+            (self.data, self.len)
+        }
+    }
+
+    impl<const N: usize> Deref for Message<N> {
+        type Target = [ApexByte];
+
+        fn deref(&self) -> &Self::Target {
+            &self.data[..self.len]
+        }
+    }
+
+    impl<const N: usize> AsRef<[ApexByte]> for Message<N> {
+        fn as_ref(&self) -> &[ApexByte] {
+            self
+        }
+    }
+
+    /// Plain old data, which is transferred through APEX objects as its in-memory representation
+    ///
+    /// The size of the encoded message is always [PodExt::SIZE].
+    ///
+    /// # Safety
+    /// Implementors must be free of padding bytes, pointers and references
+    /// and every bit pattern must be a valid value of the type.
+    /// This is usually achieved with a `#[repr(C)]` struct of [Pod] fields without padding in between.
+    pub unsafe trait Pod: Copy + 'static {
+        /// Value with all bytes set to zero
+        fn zeroed() -> Self {
+            // This is safe because every bit pattern is a valid value
+            unsafe { core::mem::zeroed() }
+        }
+
+        /// In-memory representation of this value
+        fn as_bytes(&self) -> &[ApexByte] {
+            // This is safe because the type is free of padding bytes
+            unsafe {
+                core::slice::from_raw_parts(
+                    (self as *const Self).cast::<ApexByte>(),
+                    core::mem::size_of::<Self>(),
+                )
+            }
+        }
+
+        /// Mutable in-memory representation of this value
+        fn as_bytes_mut(&mut self) -> &mut [ApexByte] {
+            // This is safe because every bit pattern is a valid value
+            unsafe {
+                core::slice::from_raw_parts_mut(
+                    (self as *mut Self).cast::<ApexByte>(),
+                    core::mem::size_of::<Self>(),
+                )
+            }
+        }
+
+        /// # Errors
+        /// - [Error::ReadError]: the length of `bytes` is not [PodExt::SIZE]
+        fn from_bytes(bytes: &[ApexByte]) -> Result<Self, Error> {
+            let mut value = Self::zeroed();
+            if bytes.len() != core::mem::size_of::<Self>() {
+                return Err(Error::ReadError);
+            }
+            value.as_bytes_mut().copy_from_slice(bytes);
+            Ok(value)
+        }
+    }
+
+    /// Size of a [Pod], which can not be overridden as it is implemented for all [Pod] types
+    pub trait PodExt: Pod {
+        /// Size of the encoded message in bytes
+        ///
+        /// Using this fails to compile for types larger than [MessageSize::MAX] bytes.
+        const SIZE: MessageSize;
+    }
+
+    impl<T: Pod> PodExt for T {
+        const SIZE: MessageSize = {
+            assert!(
+                core::mem::size_of::<T>() <= MessageSize::MAX as usize,
+                "Pod type is too large for a message"
+            );
+            core::mem::size_of::<T>() as MessageSize
+        };
+    }
+
+    macro_rules! impl_pod {
+        ($($t:ty),*) => {
+            $(unsafe impl Pod for $t {})*
+        };
+    }
+
+    impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+    unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
 }
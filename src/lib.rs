@@ -1,4 +1,10 @@
 //! Abstraction layer for the ARINC653 P1/P2/P4 API
+//!
+//! # Features
+//! - `panic-free`: removes all functions which panic if the hypervisor reports an unexpected error,
+//!   leaving only their fallible `try_*` variants.
+//!   As cargo unifies features, enabling it removes these functions for every crate in the build.
+//!   Hence it must only be enabled by the final partition binary, never by a library.
 
 #![no_std]
 #![warn(clippy::missing_errors_doc)]
@@ -3,6 +3,8 @@ pub use crate::apex::buffer::abstraction::*;
 pub use crate::apex::error::abstraction::*;
 pub use crate::apex::event::abstraction::*;
 pub use crate::apex::limits;
+#[cfg(feature = "log")]
+pub use crate::apex::logger::abstraction::*;
 pub use crate::apex::memory_block::abstraction::*;
 pub use crate::apex::mutex::abstraction::*;
 pub use crate::apex::partition::abstraction::*;
@@ -12,6 +14,7 @@ pub use crate::apex::sampling::abstraction::*;
 pub use crate::apex::schedules::abstraction::*;
 pub use crate::apex::semaphore::abstraction::*;
 pub use crate::apex::time::abstraction::*;
+pub use crate::apex::timer::abstraction::*;
 pub use crate::apex::types::abstraction::*;
 // pub use crate::apex::file_system::abstraction::*;
 // pub use crate::apex::interrupt::abstraction::*;